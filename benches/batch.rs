@@ -0,0 +1,57 @@
+use std::{convert::TryFrom, env, fs};
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+
+use lib_tcstring::{BatchDecoder, TcModelV2};
+
+/// Environment variable with the path to a corpus file (one TCString per line), e.g. sampled from
+/// production traffic
+///
+/// Defaults to `benches/data/tc_strings.txt` which is generated by `examples/bench_corpus.rs`,
+/// regenerate it with `cargo run --example bench_corpus > benches/data/tc_strings.txt`
+const CORPUS_ENV: &str = "TCSTRING_BENCH_CORPUS";
+
+fn load_corpus() -> String {
+    let path = env::var(CORPUS_ENV).unwrap_or_else(|_| {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/benches/data/tc_strings.txt").to_owned()
+    });
+
+    fs::read_to_string(&path).unwrap_or_else(|err| panic!("can't read corpus {}: {}", path, err))
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let corpus = load_corpus();
+    let corpus: Vec<&str> = corpus.lines().filter(|line| !line.is_empty()).collect();
+    let mut group = c.benchmark_group("batch decoding");
+
+    group.throughput(Throughput::Elements(corpus.len() as u64));
+    group.bench_function("TcModelV2::try_from", |b| {
+        b.iter(|| {
+            black_box(&corpus)
+                .iter()
+                .map(|tc_string| TcModelV2::try_from(*tc_string))
+                .filter(Result::is_ok)
+                .count()
+        })
+    });
+    group.bench_function("BatchDecoder::decode_iter", |b| {
+        let mut decoder = BatchDecoder::default();
+
+        b.iter(|| {
+            decoder
+                .decode_iter(black_box(&corpus))
+                .filter(Result::is_ok)
+                .count()
+        })
+    });
+    #[cfg(feature = "rayon")]
+    group.bench_function("BatchDecoder::par_decode", |b| {
+        let decoder = BatchDecoder::default();
+
+        b.iter(|| decoder.par_decode(black_box(&corpus)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
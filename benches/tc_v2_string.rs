@@ -2,7 +2,7 @@ use std::convert::TryFrom;
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
-use lib_tcstring::TcModelV2;
+use lib_tcstring::{TcModelV2, TcStringView};
 
 fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("TCString V2 (core only)", |b| {
@@ -24,6 +24,12 @@ fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("TCString V2 (core + disclosed vendors + allowed vendors + publisher tc)", |b| {
         b.iter(|| TcModelV2::try_from(black_box("COw4XqLOw4XqLAAAAAENAXCf-v-gAAAfwIAAACngAI8AEFABgACAA4A.IAPPwAPrwA.QAPPwAPrwA.cAEAPAAAC7gAHw4AAA")))
     });
+    c.bench_function("TCString V2 view (vendor consent + purpose consent)", |b| {
+        b.iter(|| {
+            TcStringView::try_from(black_box("COw4XqLOw4XqLAAAAAENAXCf-v-gAAAfwIAAACngAI8AEFABgACAA4A.IAPPwAPrwA.QAPPwAPrwA.cAEAPAAAC7gAHw4AAA"))
+                .map(|tc_view| tc_view.has_vendor_consent(755) && tc_view.purpose_consent(1))
+        })
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -0,0 +1,144 @@
+//! Generates the corpus of the `batch` benchmark
+//!
+//! ```sh
+//! cargo run --example bench_corpus > benches/data/tc_strings.txt
+//! ```
+//!
+//! The TCStrings are encoded from models with a GVL sized vendor list: ~60% accept all,
+//! ~20% reject all, the rest partial consent, ~25% with publisher restrictions, ~10% with
+//! publisher purposes and ~2% truncated. A fixed seed keeps the corpus reproducible.
+
+use lib_tcstring::{PublisherRestrictionType, TcModelV2Builder, TcsError};
+
+const CORPUS_SIZE: usize = 400;
+const SEED: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// xorshift64, good enough to spread the generated values
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, max: u64) -> u64 {
+        self.next() % max
+    }
+
+    fn chance(&mut self, percent: u64) -> bool {
+        self.below(100) < percent
+    }
+
+    fn pick<T: Copy>(&mut self, values: &[T]) -> T {
+        values[self.below(values.len() as u64) as usize]
+    }
+}
+
+fn tc_string(rng: &mut Rng, vendor_ids: &[u16]) -> Result<String, TcsError> {
+    let kind = rng.below(100);
+    let created_at = 1_650_000_000_000 + rng.below(100_000_000_000);
+    let mut builder = TcModelV2Builder::default()
+        .created_at(created_at)?
+        .updated_at(created_at + rng.below(10_000_000_000))?
+        .cmp_id(rng.pick(&[10, 21, 28, 31, 68, 300, 7]))?
+        .cmp_version(1 + rng.below(40) as u16)?
+        .consent_screen(1 + rng.below(3) as u8)?
+        .language(rng.pick(&["en", "de", "fr", "it", "es", "nl", "pl"]))?
+        .vendor_list_version(100 + rng.below(150) as u16)?
+        .tcf_policy_version(if rng.chance(80) { 4 } else { 2 })?
+        .service_specific(rng.chance(90))
+        .publisher_country_code(rng.pick(&["AA", "DE", "FR", "GB", "IT"]))?;
+
+    for &vendor_id in vendor_ids {
+        builder = builder.disclosed_vendor(vendor_id)?;
+    }
+
+    if kind < 60 {
+        // accept all
+        for purpose_id in 1..=10 {
+            builder = builder.consent_purpose(purpose_id)?;
+        }
+
+        for purpose_id in [2, 7, 8, 9, 10] {
+            builder = builder.legitimate_interest_purpose(purpose_id)?;
+        }
+
+        builder = builder.special_feature_opt_in(1)?;
+
+        for &vendor_id in vendor_ids {
+            builder = builder.vendor_consent(vendor_id)?;
+
+            if rng.chance(55) {
+                builder = builder.vendor_legitimate_interest(vendor_id)?;
+            }
+        }
+    } else if kind >= 80 {
+        // partial consent, reject all doesn't set any purposes or vendors
+        for purpose_id in 1..=10 {
+            if rng.chance(50) {
+                builder = builder.consent_purpose(purpose_id)?;
+            }
+        }
+
+        for purpose_id in [2, 7, 8, 9, 10] {
+            if rng.chance(60) {
+                builder = builder.legitimate_interest_purpose(purpose_id)?;
+            }
+        }
+
+        let consent_share = rng.below(100);
+
+        for &vendor_id in vendor_ids {
+            if rng.below(100) < consent_share {
+                builder = builder.vendor_consent(vendor_id)?;
+            }
+
+            if rng.chance(40) {
+                builder = builder.vendor_legitimate_interest(vendor_id)?;
+            }
+        }
+    }
+
+    if rng.chance(25) {
+        let restricted_vendor_ids: Vec<u16> = (0..1 + rng.below(20))
+            .map(|_| rng.pick(vendor_ids))
+            .collect();
+
+        builder = builder.restrict(
+            1 + rng.below(10) as u8,
+            PublisherRestrictionType::RequireConsent,
+            &restricted_vendor_ids,
+        )?;
+    }
+
+    if rng.chance(10) {
+        for purpose_id in 1..=10 {
+            if rng.chance(50) {
+                builder = builder.publisher_consent_purpose(purpose_id)?;
+            }
+        }
+    }
+
+    let tc_string = builder.build().encode()?;
+
+    if rng.chance(2) {
+        Ok(tc_string[..tc_string.len() / 3].to_owned())
+    } else {
+        Ok(tc_string)
+    }
+}
+
+fn main() -> Result<(), TcsError> {
+    let mut rng = Rng(SEED);
+    // ~65% of the vendor IDs up to 1150 are registered, similar to the global vendor list
+    let vendor_ids: Vec<u16> = (1..=1150).filter(|_| rng.chance(65)).collect();
+
+    for _ in 0..CORPUS_SIZE {
+        println!("{}", tc_string(&mut rng, &vendor_ids)?);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+#![no_main]
+
+use std::convert::TryFrom;
+
+use lib_tcstring::{
+    AdditionalConsent, DecodeOptions, GppString, Segment, TcModel, TcModelV1, TcModelV2,
+    TcStringView, UsPrivacy,
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = TcModelV2::try_from(data);
+    let _ = Segment::try_from(data);
+
+    if let Ok(val) = std::str::from_utf8(data) {
+        let _ = TcModel::try_from(val);
+        let _ = TcModelV1::try_from(val);
+        let _ = TcModelV2::try_from(val);
+        let _ = TcModelV2::decode_with_options(val, &DecodeOptions { strict: true });
+
+        if let Ok(tc_view) = TcStringView::try_from(val) {
+            let _ = tc_view.has_vendor_consent(u16::MAX);
+            let _ = tc_view.has_vendor_legitimate_interest(1);
+            let _ = tc_view.purpose_consent(24);
+        }
+
+        let _ = GppString::try_from(val);
+        let _ = AdditionalConsent::try_from(val);
+        let _ = UsPrivacy::try_from(val);
+    }
+});
@@ -0,0 +1,582 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Write as _},
+    io::{BufRead, Write as _},
+    process::ExitCode,
+};
+
+use lib_tcstring::{
+    gvl::VendorList, Change, DecodeOptions, IdChanges, LocatedError, PublisherRestrictionType,
+    TcModelV2, TcModelV2Diff,
+};
+
+const USAGE: &str = "Usage: tcstring [OPTIONS] [TCSTRING]...
+       tcstring diff [OPTIONS] <OLD> <NEW>
+
+Decodes IAB TCF v2 TCStrings locally, reads one TCString per line from stdin if none is given
+`diff` prints the changes from the OLD to the NEW TCString
+
+Options:
+      --json        Prints JSON instead of a table
+      --gvl <FILE>  Resolves purpose, feature and vendor names with a local global vendor list (vendor-list.json)
+  -h, --help        Prints this help";
+const FIELD_WIDTH: usize = 36;
+
+#[derive(Default)]
+struct Args {
+    diff: bool,
+    json: bool,
+    gvl_path: Option<String>,
+    tc_strings: Vec<String>,
+}
+
+fn parse_args<I: Iterator<Item = String>>(args: I) -> Result<Option<Args>, String> {
+    let mut args = args.peekable();
+    let mut parsed_args = Args {
+        diff: args.next_if(|arg| arg == "diff").is_some(),
+        ..Args::default()
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--json" => parsed_args.json = true,
+            "--gvl" => {
+                parsed_args.gvl_path = Some(
+                    args.next()
+                        .ok_or_else(|| String::from("--gvl requires a file"))?,
+                )
+            }
+            _ if arg.starts_with('-') => return Err(format!("unknown option {}", arg)),
+            _ => parsed_args.tc_strings.push(arg),
+        }
+    }
+
+    Ok(Some(parsed_args))
+}
+
+struct Names<'a> {
+    purposes: BTreeMap<u8, &'a str>,
+    special_features: BTreeMap<u8, &'a str>,
+    vendors: BTreeMap<u16, &'a str>,
+}
+
+impl<'a> Names<'a> {
+    fn new(vendor_list: &'a VendorList) -> Self {
+        Self {
+            purposes: vendor_list
+                .purposes
+                .iter()
+                .map(|(id, purpose)| (*id, purpose.name.as_str()))
+                .collect(),
+            special_features: vendor_list
+                .special_features
+                .iter()
+                .map(|(id, feature)| (*id, feature.name.as_str()))
+                .collect(),
+            vendors: vendor_list
+                .vendors
+                .iter()
+                .map(|(id, vendor)| (*id, vendor.name.as_str()))
+                .collect(),
+        }
+    }
+}
+
+fn format_ids<T: Ord + Display>(
+    ids: impl IntoIterator<Item = T>,
+    names: Option<&BTreeMap<T, &str>>,
+) -> Vec<String> {
+    ids.into_iter()
+        .map(|id| match names.and_then(|names| names.get(&id)) {
+            Some(name) => format!("{} ({})", id, name),
+            None => id.to_string(),
+        })
+        .collect()
+}
+
+fn write_row(out: &mut String, field: &str, values: &[String], one_per_line: bool) {
+    let values = match values {
+        [] => vec![String::from("-")],
+        _ if one_per_line => values.to_vec(),
+        _ => vec![values.join(", ")],
+    };
+
+    for (index, value) in values.iter().enumerate() {
+        let field = if index == 0 { field } else { "" };
+
+        let _ = writeln!(out, "{:width$}{}", field, value, width = FIELD_WIDTH);
+    }
+}
+
+fn restriction_type_label(restriction_type: &PublisherRestrictionType) -> &'static str {
+    match restriction_type {
+        PublisherRestrictionType::NotAllowed => "not allowed",
+        PublisherRestrictionType::RequireConsent => "requires consent",
+        PublisherRestrictionType::RequireLegitimateInterest => "requires legitimate interest",
+        PublisherRestrictionType::Undefined => "undefined",
+    }
+}
+
+fn format_id_changes<T: Ord + Display + Copy>(
+    id_changes: &IdChanges<T>,
+    names: Option<&BTreeMap<T, &str>>,
+) -> Vec<String> {
+    format_ids(id_changes.added.iter().copied(), names)
+        .into_iter()
+        .map(|id| format!("+{}", id))
+        .chain(
+            format_ids(id_changes.removed.iter().copied(), names)
+                .into_iter()
+                .map(|id| format!("-{}", id)),
+        )
+        .collect()
+}
+
+fn format_change<T: Display>(change: &Change<T>) -> String {
+    format!("{} -> {}", change.old, change.new)
+}
+
+fn format_diff(diff: &TcModelV2Diff, names: Option<&Names>) -> String {
+    if diff.is_empty() {
+        return String::from("no changes\n");
+    }
+
+    let purposes = names.map(|names| &names.purposes);
+    let vendors = names.map(|names| &names.vendors);
+    let one_per_line = names.is_some();
+    let mut out = String::new();
+
+    for (field, value) in [
+        ("created_at", diff.created_at.as_ref().map(format_change)),
+        ("updated_at", diff.updated_at.as_ref().map(format_change)),
+        ("cmp_id", diff.cmp_id.as_ref().map(format_change)),
+        ("cmp_version", diff.cmp_version.as_ref().map(format_change)),
+        (
+            "consent_screen",
+            diff.consent_screen.as_ref().map(format_change),
+        ),
+        (
+            "consent_language",
+            diff.consent_language.as_ref().map(format_change),
+        ),
+        (
+            "vendor_list_version",
+            diff.vendor_list_version.as_ref().map(format_change),
+        ),
+        (
+            "tcf_policy_version",
+            diff.tcf_policy_version.as_ref().map(format_change),
+        ),
+        (
+            "is_service_specific",
+            diff.is_service_specific.as_ref().map(format_change),
+        ),
+        (
+            "use_non_standard_stacks",
+            diff.use_non_standard_stacks.as_ref().map(format_change),
+        ),
+        (
+            "purpose_one_treatment",
+            diff.purpose_one_treatment.as_ref().map(format_change),
+        ),
+        (
+            "publisher_country_code",
+            diff.publisher_country_code.as_ref().map(format_change),
+        ),
+    ] {
+        if let Some(value) = value {
+            write_row(&mut out, field, &[value], false);
+        }
+    }
+
+    for (field, id_changes, names) in [
+        (
+            "special_feature_opt_ins",
+            &diff.special_feature_opt_ins,
+            names.map(|names| &names.special_features),
+        ),
+        ("purposes_consent", &diff.purposes_consent, purposes),
+        (
+            "purposes_li_transparency",
+            &diff.purposes_li_transparency,
+            purposes,
+        ),
+        (
+            "publisher_purposes_consent",
+            &diff.publisher_purposes_consent,
+            purposes,
+        ),
+        (
+            "publisher_purposes_li_transparency",
+            &diff.publisher_purposes_li_transparency,
+            purposes,
+        ),
+        (
+            "custom_purposes_consent",
+            &diff.custom_purposes_consent,
+            None,
+        ),
+        (
+            "custom_purposes_li_transparency",
+            &diff.custom_purposes_li_transparency,
+            None,
+        ),
+    ] {
+        if let Some(id_changes) = id_changes {
+            write_row(
+                &mut out,
+                field,
+                &format_id_changes(id_changes, names),
+                one_per_line,
+            );
+        }
+    }
+
+    for (field, id_changes) in [
+        ("vendors_consent", &diff.vendors_consent),
+        ("vendors_li_consent", &diff.vendors_li_consent),
+        ("disclosed_vendors", &diff.disclosed_vendors),
+        ("allowed_vendors", &diff.allowed_vendors),
+    ] {
+        if let Some(id_changes) = id_changes {
+            write_row(
+                &mut out,
+                field,
+                &format_id_changes(id_changes, vendors),
+                one_per_line,
+            );
+        }
+    }
+
+    if !diff.publisher_restrictions.is_empty() {
+        let restrictions: Vec<String> = diff
+            .publisher_restrictions
+            .iter()
+            .map(|restriction_change| {
+                format!(
+                    "purpose {} {}: {}",
+                    restriction_change.purpose_id,
+                    restriction_type_label(&restriction_change.restriction_type),
+                    format_id_changes(&restriction_change.vendors, vendors).join(", ")
+                )
+            })
+            .collect();
+
+        write_row(&mut out, "publisher_restrictions", &restrictions, true);
+    }
+
+    out
+}
+
+fn format_table(tc_model: &TcModelV2, names: Option<&Names>) -> String {
+    let purposes = names.map(|names| &names.purposes);
+    let vendors = names.map(|names| &names.vendors);
+    let one_per_line = names.is_some();
+    let mut out = String::new();
+
+    for (field, value) in [
+        ("created_at", tc_model.created_at.to_string()),
+        ("updated_at", tc_model.updated_at.to_string()),
+        ("cmp_id", tc_model.cmp_id.to_string()),
+        ("cmp_version", tc_model.cmp_version.to_string()),
+        ("consent_screen", tc_model.consent_screen.to_string()),
+        ("consent_language", tc_model.consent_language.clone()),
+        (
+            "vendor_list_version",
+            tc_model.vendor_list_version.to_string(),
+        ),
+        (
+            "tcf_policy_version",
+            tc_model.tcf_policy_version.to_string(),
+        ),
+        (
+            "is_service_specific",
+            tc_model.is_service_specific.to_string(),
+        ),
+        (
+            "use_non_standard_stacks",
+            tc_model.use_non_standard_stacks.to_string(),
+        ),
+        (
+            "purpose_one_treatment",
+            tc_model.purpose_one_treatment.to_string(),
+        ),
+        (
+            "publisher_country_code",
+            tc_model.publisher_country_code.clone(),
+        ),
+    ] {
+        write_row(&mut out, field, &[value], false);
+    }
+
+    for (field, ids, names) in [
+        (
+            "special_feature_opt_ins",
+            &tc_model.special_feature_opt_ins,
+            names.map(|names| &names.special_features),
+        ),
+        ("purposes_consent", &tc_model.purposes_consent, purposes),
+        (
+            "purposes_li_transparency",
+            &tc_model.purposes_li_transparency,
+            purposes,
+        ),
+        (
+            "publisher_purposes_consent",
+            &tc_model.publisher_purposes_consent,
+            purposes,
+        ),
+        (
+            "publisher_purposes_li_transparency",
+            &tc_model.publisher_purposes_li_transparency,
+            purposes,
+        ),
+        (
+            "custom_purposes_consent",
+            &tc_model.custom_purposes_consent,
+            None,
+        ),
+        (
+            "custom_purposes_li_transparency",
+            &tc_model.custom_purposes_li_transparency,
+            None,
+        ),
+    ] {
+        write_row(
+            &mut out,
+            field,
+            &format_ids(ids.iter().copied(), names),
+            one_per_line,
+        );
+    }
+
+    for (field, vendor_set) in [
+        ("vendors_consent", &tc_model.vendors_consent),
+        ("vendors_li_consent", &tc_model.vendors_li_consent),
+        ("disclosed_vendors", &tc_model.disclosed_vendors),
+        ("allowed_vendors", &tc_model.allowed_vendors),
+    ] {
+        write_row(
+            &mut out,
+            field,
+            &format_ids(vendor_set.iter(), vendors),
+            one_per_line,
+        );
+    }
+
+    let restrictions: Vec<String> = tc_model
+        .publisher_restrictions
+        .iter()
+        .map(|publisher_restriction| {
+            format!(
+                "purpose {} {}: {}",
+                publisher_restriction.purpose_id,
+                restriction_type_label(&publisher_restriction.restriction_type),
+                format_ids(publisher_restriction.vendor_list.iter(), vendors).join(", ")
+            )
+        })
+        .collect();
+
+    write_row(&mut out, "publisher_restrictions", &restrictions, true);
+
+    out
+}
+
+fn decode(tc_string: &str) -> Result<TcModelV2, LocatedError> {
+    TcModelV2::decode_with_location(tc_string, &DecodeOptions::default())
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{}", USAGE);
+
+            return ExitCode::SUCCESS;
+        }
+        Err(err) => {
+            eprintln!("error: {}\n\n{}", err, USAGE);
+
+            return ExitCode::from(2);
+        }
+    };
+    let vendor_list = match args
+        .gvl_path
+        .as_deref()
+        .map(VendorList::from_path)
+        .transpose()
+    {
+        Ok(vendor_list) => vendor_list,
+        Err(err) => {
+            eprintln!("error: {}", err);
+
+            return ExitCode::from(2);
+        }
+    };
+    let names = vendor_list.as_ref().map(Names::new);
+    let tc_strings = if args.tc_strings.is_empty() {
+        std::io::stdin()
+            .lock()
+            .lines()
+            .map_while(Result::ok)
+            .map(|line| line.trim().to_string())
+            .filter(|line| !line.is_empty())
+            .collect()
+    } else {
+        args.tc_strings
+    };
+    let mut stdout = std::io::stdout().lock();
+
+    if args.diff {
+        let [old, new] = tc_strings.as_slice() else {
+            eprintln!("error: diff requires exactly two TCStrings\n\n{}", USAGE);
+
+            return ExitCode::from(2);
+        };
+        let output = decode(old)
+            .map_err(|err| format!("{}: {}", old, err))
+            .and_then(|old| {
+                decode(new)
+                    .map(|new| old.diff(&new))
+                    .map_err(|err| format!("{}: {}", new, err))
+            })
+            .and_then(|diff| {
+                if args.json {
+                    serde_json::to_string_pretty(&diff)
+                        .map(|json| json + "\n")
+                        .map_err(|err| err.to_string())
+                } else {
+                    Ok(format_diff(&diff, names.as_ref()))
+                }
+            });
+
+        return match output {
+            Ok(output) => {
+                let _ = write!(stdout, "{}", output);
+
+                ExitCode::SUCCESS
+            }
+            Err(err) => {
+                eprintln!("error: {}", err);
+
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    let mut exit_code = ExitCode::SUCCESS;
+
+    for (index, tc_string) in tc_strings.iter().enumerate() {
+        let output = decode(tc_string)
+            .map_err(|err| err.to_string())
+            .and_then(|tc_model| {
+                if args.json {
+                    serde_json::to_string_pretty(&tc_model)
+                        .map(|json| json + "\n")
+                        .map_err(|err| err.to_string())
+                } else {
+                    Ok(format_table(&tc_model, names.as_ref()))
+                }
+            });
+
+        match output {
+            Ok(output) => {
+                let separator = if index > 0 && !args.json { "\n" } else { "" };
+
+                // stop once stdout is closed, e.g. when piped into `head`
+                if write!(stdout, "{}{}", separator, output).is_err() {
+                    break;
+                }
+            }
+            Err(err) => {
+                eprintln!("error: {}: {}", tc_string, err);
+                exit_code = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    exit_code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(val: &[&str]) -> Result<Option<Args>, String> {
+        parse_args(val.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn cli_parse_args() {
+        let parsed_args = args(&["--json", "--gvl", "vendor-list.json", "CAAA", "CBBB"])
+            .unwrap()
+            .unwrap();
+
+        assert!(parsed_args.json);
+        assert_eq!(parsed_args.gvl_path.as_deref(), Some("vendor-list.json"));
+        assert_eq!(parsed_args.tc_strings, vec!["CAAA", "CBBB"]);
+        assert!(!parsed_args.diff);
+        assert!(args(&["diff", "CAAA", "CBBB"]).unwrap().unwrap().diff);
+        assert!(args(&["--help"]).unwrap().is_none());
+        assert!(args(&["--gvl"]).is_err());
+        assert!(args(&["--yaml"]).is_err());
+    }
+
+    #[test]
+    fn cli_format_table() {
+        let tc_model = TcModelV2::try_from(
+            "COw4XqLOw4XqLAAAAAENAXCf-v-gAAAfwIAAACngAI8AEFABgACAA4A.IAPPwAPrwA.QAPPwAPrwA.cAEAPAAAC7gAHw4AAA",
+        )
+        .unwrap();
+        let vendor_list: VendorList = r#"{
+            "gvlSpecificationVersion": 2,
+            "vendorListVersion": 23,
+            "tcfPolicyVersion": 2,
+            "lastUpdated": "2020-03-26T17:00:00Z",
+            "purposes": {},
+            "specialPurposes": {},
+            "features": {},
+            "specialFeatures": {},
+            "vendors": {
+                "2": {
+                    "id": 2,
+                    "name": "Vendor 2",
+                    "purposes": [1],
+                    "legIntPurposes": [],
+                    "flexiblePurposes": [],
+                    "specialPurposes": [],
+                    "features": [],
+                    "specialFeatures": []
+                }
+            }
+        }"#
+        .parse()
+        .unwrap();
+        let table = format_table(&tc_model, None);
+        let named_table = format_table(&tc_model, Some(&Names::new(&vendor_list)));
+
+        assert!(table.contains("\nvendors_consent                     2, 3, 4, 5\n"));
+        assert!(table.contains(
+            "\npublisher_restrictions              purpose 1 requires consent: 1, 2, 3, 4, 5, 6, 7\n"
+        ));
+        assert!(named_table.contains("\nvendors_consent                     2 (Vendor 2)\n"));
+        assert!(named_table.contains("\n                                    3\n"));
+    }
+
+    #[test]
+    fn cli_format_diff() {
+        let old = TcModelV2::try_from("COvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAAAEEUACCKAAA").unwrap();
+        let new = TcModelV2::try_from(
+            "COw4XqLOw4XqLAAAAAENAXCf-v-gAAAfwIAAACngAI8AEFABgACAA4A.IAPPwAPrwA.QAPPwAPrwA.cAEAPAAAC7gAHw4AAA",
+        )
+        .unwrap();
+        let diff = format_diff(&old.diff(&new), None);
+
+        assert_eq!(format_diff(&old.diff(&old), None), "no changes\n");
+        assert!(diff.contains("\ncmp_id                              27 -> 0\n"));
+        assert!(diff.contains(
+            "\npublisher_restrictions              purpose 1 requires consent: +1, +2, +3, +4, +5, +6, +7\n"
+        ));
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::BTreeMap;
+
+/// `TCData` object as returned by the CMP API v2 command `__tcfapi('getTCData')`
+///
+/// Created from a [`TcModelV2`] which can be encoded into a TCString, serializes to the exact shape of the [`CMP API v2`] (`purpose.consents`, `vendor.legitimateInterests`, `publisher.restrictions`, ...)
+/// where every ID-keyed map contains the IDs from `1` up to the highest set ID.
+///
+/// ```rust,edition2021
+/// use std::convert::TryFrom;
+/// use lib_tcstring::{TcData, TcModelV2};
+/// let tc_model = TcModelV2::try_from("COvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAAAEEUACCKAAA").unwrap();
+/// let tc_data = TcData::try_from(&tc_model).unwrap();
+///
+/// assert_eq!(tc_data.vendor.consents.get(&8), Some(&true));
+/// assert_eq!(tc_data.vendor.consents.get(&7), Some(&false));
+/// assert_eq!(tc_data.vendor.consents.get(&9), None);
+/// ```
+///
+/// [`TcModelV2`]: struct.TcModelV2.html
+/// [`CMP API v2`]: https://github.com/InteractiveAdvertisingBureau/GDPR-Transparency-and-Consent-Framework/blob/master/TCFv2/IAB%20Tech%20Lab%20-%20CMP%20API%20v2.md#tcdata
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(PartialEq, Eq, Clone, PartialOrd, Hash, Debug, Default)]
+pub struct TcData {
+    /// Encoded TCString
+    pub tc_string: String,
+    /// Version of the TCF policy
+    pub tcf_policy_version: u16,
+    /// ID of the CMP
+    pub cmp_id: u16,
+    /// Version of the CMP
+    pub cmp_version: u16,
+    /// Whether GDPR applies, omitted if unknown
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub gdpr_applies: Option<bool>,
+    /// Status of the CMP event, e.g. `tcloaded`
+    pub event_status: String,
+    /// Status of the CMP, e.g. `loaded`
+    pub cmp_status: String,
+    /// ID of the event listener, omitted for `getTCData`
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub listener_id: Option<u32>,
+    /// Whether the TCString is service specific
+    pub is_service_specific: bool,
+    /// Whether the CMP used non-IAB standard texts
+    pub use_non_standard_texts: bool,
+    /// Country code of the publisher
+    #[cfg_attr(feature = "serde", serde(rename = "publisherCC"))]
+    pub publisher_cc: String,
+    /// Whether purpose 1 wasn't disclosed
+    pub purpose_one_treatment: bool,
+    /// Vendors which are allowed or disclosed to use out-of-band legal bases
+    pub out_of_band: TcDataOutOfBand,
+    /// Purpose consents and legitimate interests
+    pub purpose: TcDataConsents<u8>,
+    /// Vendor consents and legitimate interests
+    pub vendor: TcDataConsents<u16>,
+    /// Special feature opt-ins
+    pub special_feature_optins: BTreeMap<u8, bool>,
+    /// Publisher consents, legitimate interests and restrictions
+    pub publisher: TcDataPublisher,
+}
+
+/// Out-of-band vendors of a [`TcData`]
+///
+/// [`TcData`]: struct.TcData.html
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(PartialEq, Eq, Clone, PartialOrd, Hash, Debug, Default)]
+pub struct TcDataOutOfBand {
+    /// Vendors which are allowed to use out-of-band legal bases
+    pub allowed_vendors: BTreeMap<u16, bool>,
+    /// Vendors which were disclosed to the user
+    pub disclosed_vendors: BTreeMap<u16, bool>,
+}
+
+/// Consents and legitimate interests keyed by purpose or vendor ID
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(PartialEq, Eq, Clone, PartialOrd, Hash, Debug, Default)]
+pub struct TcDataConsents<T: Ord> {
+    /// Consent per ID
+    pub consents: BTreeMap<T, bool>,
+    /// Established legitimate interest per ID
+    pub legitimate_interests: BTreeMap<T, bool>,
+}
+
+/// Publisher section of a [`TcData`]
+///
+/// [`TcData`]: struct.TcData.html
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(PartialEq, Eq, Clone, PartialOrd, Hash, Debug, Default)]
+pub struct TcDataPublisher {
+    /// Publisher purpose consents
+    pub consents: BTreeMap<u8, bool>,
+    /// Publisher purpose legitimate interests
+    pub legitimate_interests: BTreeMap<u8, bool>,
+    /// Custom purpose consents and legitimate interests
+    pub custom_purpose: TcDataConsents<u8>,
+    /// Restriction type (`0` not allowed, `1` require consent, `2` require legitimate interest) per purpose and vendor ID
+    pub restrictions: BTreeMap<u8, BTreeMap<u16, u8>>,
+}
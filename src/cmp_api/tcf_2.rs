@@ -0,0 +1,172 @@
+use std::{collections::BTreeMap, convert::TryFrom};
+
+use crate::{
+    cmp_api::model::{TcData, TcDataConsents, TcDataOutOfBand, TcDataPublisher},
+    decode::{
+        error::TcsError,
+        model::{PublisherRestrictionType, TcModelV2, VendorSet},
+    },
+};
+
+const EVENT_STATUS_TC_LOADED: &str = "tcloaded";
+const CMP_STATUS_LOADED: &str = "loaded";
+
+fn purpose_map(purpose_ids: &[u8]) -> BTreeMap<u8, bool> {
+    let max_purpose_id = purpose_ids.iter().max().copied().unwrap_or_default();
+
+    (1..=max_purpose_id)
+        .map(|purpose_id| (purpose_id, purpose_ids.contains(&purpose_id)))
+        .collect()
+}
+
+fn vendor_map(vendor_set: &VendorSet) -> BTreeMap<u16, bool> {
+    (1..=vendor_set.max_vendor_id().unwrap_or_default())
+        .map(|vendor_id| (vendor_id, vendor_set.contains(vendor_id)))
+        .collect()
+}
+
+fn restriction_map(tc_model: &TcModelV2) -> BTreeMap<u8, BTreeMap<u16, u8>> {
+    let mut restrictions: BTreeMap<u8, BTreeMap<u16, u8>> = BTreeMap::new();
+
+    for publisher_restriction in &tc_model.publisher_restrictions {
+        let restriction_type = match publisher_restriction.restriction_type {
+            PublisherRestrictionType::NotAllowed => 0,
+            PublisherRestrictionType::RequireConsent => 1,
+            PublisherRestrictionType::RequireLegitimateInterest => 2,
+            PublisherRestrictionType::Undefined => continue,
+        };
+
+        restrictions
+            .entry(publisher_restriction.purpose_id)
+            .or_default()
+            .extend(
+                publisher_restriction
+                    .vendor_list
+                    .iter()
+                    .map(|vendor_id| (vendor_id, restriction_type)),
+            );
+    }
+
+    restrictions
+}
+
+impl TryFrom<&TcModelV2> for TcData {
+    type Error = TcsError;
+
+    /// Creates the `TCData` of a loaded CMP, `tc_string` contains the re-encoded TCString
+    ///
+    /// Fails if the model can't be encoded into a TCString
+    fn try_from(tc_model: &TcModelV2) -> Result<Self, Self::Error> {
+        Ok(Self {
+            tc_string: tc_model.encode()?,
+            tcf_policy_version: tc_model.tcf_policy_version,
+            cmp_id: tc_model.cmp_id,
+            cmp_version: tc_model.cmp_version,
+            gdpr_applies: Some(true),
+            event_status: String::from(EVENT_STATUS_TC_LOADED),
+            cmp_status: String::from(CMP_STATUS_LOADED),
+            listener_id: None,
+            is_service_specific: tc_model.is_service_specific,
+            use_non_standard_texts: tc_model.use_non_standard_stacks,
+            publisher_cc: tc_model.publisher_country_code.clone(),
+            purpose_one_treatment: tc_model.purpose_one_treatment,
+            out_of_band: TcDataOutOfBand {
+                allowed_vendors: vendor_map(&tc_model.allowed_vendors),
+                disclosed_vendors: vendor_map(&tc_model.disclosed_vendors),
+            },
+            purpose: TcDataConsents {
+                consents: purpose_map(&tc_model.purposes_consent),
+                legitimate_interests: purpose_map(&tc_model.purposes_li_transparency),
+            },
+            vendor: TcDataConsents {
+                consents: vendor_map(&tc_model.vendors_consent),
+                legitimate_interests: vendor_map(&tc_model.vendors_li_consent),
+            },
+            special_feature_optins: purpose_map(&tc_model.special_feature_opt_ins),
+            publisher: TcDataPublisher {
+                consents: purpose_map(&tc_model.publisher_purposes_consent),
+                legitimate_interests: purpose_map(&tc_model.publisher_purposes_li_transparency),
+                custom_purpose: TcDataConsents {
+                    consents: purpose_map(&tc_model.custom_purposes_consent),
+                    legitimate_interests: purpose_map(&tc_model.custom_purposes_li_transparency),
+                },
+                restrictions: restriction_map(tc_model),
+            },
+        })
+    }
+}
+
+impl TryFrom<TcModelV2> for TcData {
+    type Error = TcsError;
+
+    fn try_from(tc_model: TcModelV2) -> Result<Self, Self::Error> {
+        Self::try_from(&tc_model)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TC_STRING: &str = "COw4XqLOw4XqLAAAAAENAXCf-v-gAAAfwIAAACngAI8AEFABgACAA4A.IAPPwAPrwA.QAPPwAPrwA.cAEAPAAAC7gAHw4AAA";
+
+    #[test]
+    fn tc_data_from_tc_model() {
+        let tc_model = TcModelV2::try_from(TC_STRING).unwrap();
+        let tc_data = TcData::try_from(&tc_model).unwrap();
+
+        assert_eq!(
+            TcModelV2::try_from(tc_data.tc_string.as_str()),
+            Ok(tc_model)
+        );
+        assert_eq!(
+            tc_data.vendor.consents,
+            BTreeMap::from([(1, false), (2, true), (3, true), (4, true), (5, true)])
+        );
+        assert_eq!(
+            tc_data.publisher.consents,
+            (1..=24)
+                .map(|purpose_id| (purpose_id, [1, 13, 24].contains(&purpose_id)))
+                .collect()
+        );
+        assert_eq!(
+            tc_data.publisher.restrictions,
+            BTreeMap::from([(1, (1..=7).map(|vendor_id| (vendor_id, 1)).collect())])
+        );
+        assert_eq!(tc_data.out_of_band.allowed_vendors.len(), 30);
+        assert_eq!(tc_data.event_status, "tcloaded");
+    }
+
+    #[test]
+    fn tc_data_from_unencodable_tc_model() {
+        let tc_model = TcModelV2 {
+            cmp_id: 4096,
+            ..TcModelV2::try_from(TC_STRING).unwrap()
+        };
+
+        assert_eq!(TcData::try_from(&tc_model), Err(TcsError::ValueOutOfRange));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn tc_data_json_shape() {
+        let tc_data = TcData::try_from(TcModelV2::try_from(TC_STRING).unwrap()).unwrap();
+        let json = serde_json::to_value(&tc_data).unwrap();
+
+        assert_eq!(json["tcString"], tc_data.tc_string.as_str());
+        assert_eq!(json["publisherCC"], "AA");
+        assert_eq!(json["gdprApplies"], true);
+        assert_eq!(json["useNonStandardTexts"], true);
+        assert_eq!(json["purpose"]["consents"]["1"], true);
+        assert_eq!(json["purpose"]["legitimateInterests"]["11"], false);
+        assert_eq!(json["vendor"]["legitimateInterests"]["1"], true);
+        assert_eq!(json["specialFeatureOptins"]["10"], false);
+        assert_eq!(json["outOfBand"]["disclosedVendors"]["30"], true);
+        assert_eq!(
+            json["publisher"]["customPurpose"]["legitimateInterests"]["5"],
+            true
+        );
+        assert_eq!(json["publisher"]["restrictions"]["1"]["7"], 1);
+        assert!(json.get("listenerId").is_none());
+    }
+}
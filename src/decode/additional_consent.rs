@@ -0,0 +1,119 @@
+use std::convert::TryFrom;
+
+use crate::decode::{error::TcsError, model::AdditionalConsent};
+
+const DISCLOSED_PROVIDERS_PREFIX: &str = "dv.";
+
+fn parse_provider_list(val: &str) -> Result<Vec<u16>, TcsError> {
+    if val.is_empty() {
+        return Ok(vec![]);
+    }
+
+    val.split('.')
+        .map(|provider_id| match provider_id.parse::<u16>() {
+            Ok(provider_id) if provider_id > 0 => Ok(provider_id),
+            _ => Err(TcsError::InvalidFormat),
+        })
+        .collect()
+}
+
+impl TryFrom<&str> for AdditionalConsent {
+    type Error = TcsError;
+
+    fn try_from(val: &str) -> Result<Self, Self::Error> {
+        let parts: Vec<&str> = val.split('~').collect();
+        let version = parts[0].parse::<u8>().or(Err(TcsError::InvalidFormat))?;
+
+        match (version, parts.as_slice()) {
+            (1, [_, consented_providers]) => Ok(Self {
+                version,
+                consented_providers: parse_provider_list(consented_providers)?,
+                disclosed_providers: vec![],
+            }),
+            (2, [_, consented_providers, disclosed_providers]) => Ok(Self {
+                version,
+                consented_providers: parse_provider_list(consented_providers)?,
+                disclosed_providers: parse_provider_list(
+                    disclosed_providers
+                        .strip_prefix(DISCLOSED_PROVIDERS_PREFIX)
+                        .ok_or(TcsError::InvalidFormat)?,
+                )?,
+            }),
+            (1 | 2, _) => Err(TcsError::InvalidFormat),
+            _ => Err(TcsError::UnsupportedVersion(version)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn additional_consent_v1() {
+        assert_eq!(
+            AdditionalConsent::try_from("1~1.35.41.101"),
+            Ok(AdditionalConsent {
+                version: 1,
+                consented_providers: vec![1, 35, 41, 101],
+                disclosed_providers: vec![],
+            })
+        );
+        assert_eq!(
+            AdditionalConsent::try_from("1~"),
+            Ok(AdditionalConsent {
+                version: 1,
+                consented_providers: vec![],
+                disclosed_providers: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn additional_consent_v2() {
+        assert_eq!(
+            AdditionalConsent::try_from("2~1.35.41~dv.9.21"),
+            Ok(AdditionalConsent {
+                version: 2,
+                consented_providers: vec![1, 35, 41],
+                disclosed_providers: vec![9, 21],
+            })
+        );
+        assert_eq!(
+            AdditionalConsent::try_from("2~~dv."),
+            Ok(AdditionalConsent {
+                version: 2,
+                consented_providers: vec![],
+                disclosed_providers: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn additional_consent_invalid() {
+        assert_eq!(
+            AdditionalConsent::try_from("3~1.35"),
+            Err(TcsError::UnsupportedVersion(3))
+        );
+        assert_eq!(
+            AdditionalConsent::try_from(""),
+            Err(TcsError::InvalidFormat)
+        );
+        assert_eq!(
+            AdditionalConsent::try_from("1~1.35~dv.9"),
+            Err(TcsError::InvalidFormat)
+        );
+        assert_eq!(
+            AdditionalConsent::try_from("2~1.35~9.21"),
+            Err(TcsError::InvalidFormat)
+        );
+        assert_eq!(
+            AdditionalConsent::try_from("2~1..35~dv."),
+            Err(TcsError::InvalidFormat)
+        );
+        assert_eq!(
+            AdditionalConsent::try_from("1~1.x"),
+            Err(TcsError::InvalidFormat)
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use crate::decode::{
+    error::TcsError,
+    model::{BatchDecodeIter, BatchDecoder, DecodeOptions, TcModelV2},
+    util::{decode_segments_into, parse_version_from_segments},
+};
+
+impl BatchDecoder {
+    /// Creates a `BatchDecoder` which decodes every TCString with the given options
+    pub fn new(options: DecodeOptions) -> Self {
+        Self {
+            options,
+            tcs_segments: Vec::with_capacity(4),
+        }
+    }
+
+    /// Decodes a single TCString, equivalent to [`TcModelV2::decode_with_options`]
+    ///
+    /// [`TcModelV2::decode_with_options`]: struct.TcModelV2.html#method.decode_with_options
+    pub fn decode(&mut self, val: &str) -> Result<TcModelV2, TcsError> {
+        let segment_count = decode_segments_into(val, &mut self.tcs_segments)?;
+        let tcs_segments = &self.tcs_segments[..segment_count];
+
+        match parse_version_from_segments(tcs_segments)? {
+            2 => Ok(TcModelV2::try_from_slice(tcs_segments, &self.options)?),
+            version => Err(TcsError::UnsupportedVersion(version)),
+        }
+    }
+
+    /// Returns an iterator which lazily decodes the given TCStrings in order
+    pub fn decode_iter<I>(&mut self, val: I) -> BatchDecodeIter<'_, I::IntoIter>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        BatchDecodeIter {
+            decoder: self,
+            iter: val.into_iter(),
+        }
+    }
+
+    /// Decodes the given TCStrings in parallel (requires the `rayon` feature)
+    ///
+    /// Every worker reuses its own buffers, the results are in the same order as the given TCStrings
+    #[cfg(feature = "rayon")]
+    pub fn par_decode<S>(&self, val: &[S]) -> Vec<Result<TcModelV2, TcsError>>
+    where
+        S: AsRef<str> + Sync,
+    {
+        use rayon::prelude::*;
+
+        val.par_iter()
+            .map_init(
+                || Self::new(self.options.clone()),
+                |decoder, val| decoder.decode(val.as_ref()),
+            )
+            .collect()
+    }
+}
+
+impl<I> Iterator for BatchDecodeIter<'_, I>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+    type Item = Result<TcModelV2, TcsError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let val = self.iter.next()?;
+
+        Some(self.decoder.decode(val.as_ref()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use super::*;
+    use crate::decode::fixtures::TC_STRINGS;
+
+    const INVALID_TC_STRINGS: &[&str] = &[
+        "",
+        "BOEFEAyOEFEAyAHABDENAI4AAAB9vABAASA",
+        "COvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAAAEEUACCKAAA.IA",
+    ];
+
+    fn tc_strings() -> Vec<&'static str> {
+        TC_STRINGS
+            .iter()
+            .chain(INVALID_TC_STRINGS)
+            .copied()
+            .collect()
+    }
+
+    #[test]
+    fn batch_decoder_matches_try_from() {
+        let mut decoder = BatchDecoder::default();
+        let tc_strings = tc_strings();
+
+        assert_eq!(
+            decoder.decode_iter(&tc_strings).collect::<Vec<_>>(),
+            tc_strings
+                .iter()
+                .map(|tc_string| TcModelV2::try_from(*tc_string))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn batch_decoder_uses_options() {
+        let mut decoder = BatchDecoder::new(DecodeOptions { strict: true });
+
+        for tc_string in tc_strings() {
+            assert_eq!(
+                decoder.decode(tc_string),
+                TcModelV2::decode_with_options(tc_string, &DecodeOptions { strict: true })
+            );
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn batch_decoder_par_decode() {
+        let decoder = BatchDecoder::default();
+        let tc_strings: Vec<&str> = tc_strings().into_iter().cycle().take(500).collect();
+
+        assert_eq!(
+            decoder.par_decode(&tc_strings),
+            BatchDecoder::default()
+                .decode_iter(&tc_strings)
+                .collect::<Vec<_>>()
+        );
+    }
+}
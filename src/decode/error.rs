@@ -12,14 +12,49 @@ pub const INVALID_ALPHABET_OFFSET: &str = "ERR_INVALID_ALPHABET_OFFSET";
 pub const INVALID_SECTION_DEFINITION: &str = "ERR_INVALID_SECTION_DEFINITION";
 pub const INVALID_SEGMENT_DEFINITION: &str = "ERR_INVALID_SEGMENT_DEFINITION";
 pub const UNEXPECTED_RANGE_SECTION: &str = "ERR_UNEXPECTED_RANGE_SECTION";
+pub const VALUE_OUT_OF_RANGE: &str = "ERR_VALUE_OUT_OF_RANGE";
+pub const INVALID_FORMAT: &str = "ERR_INVALID_FORMAT";
+pub const INVALID_RESTRICTION_TYPE: &str = "ERR_INVALID_RESTRICTION_TYPE";
+pub const INVALID_TIMESTAMPS: &str = "ERR_INVALID_TIMESTAMPS";
+pub const INVALID_VENDOR_RANGE: &str = "ERR_INVALID_VENDOR_RANGE";
+pub const NON_ZERO_PADDING: &str = "ERR_NON_ZERO_PADDING";
+pub const DUPLICATE_SEGMENT: &str = "ERR_DUPLICATE_SEGMENT";
+pub const UNSUPPORTED_POLICY_VERSION: &str = "ERR_UNSUPPORTED_POLICY_VERSION";
 
-/// Errors that can occur while decoding the TCString
+/// Segments of a TCString
+#[derive(PartialEq, Eq, Clone, Copy, PartialOrd, Hash, Debug)]
+pub enum SegmentType {
+    /// Core segment, the first segment of every TCString
+    Core,
+    /// "Disclosed Vendors" segment
+    DisclosedVendors,
+    /// "Allowed Vendors" segment
+    AllowedVendors,
+    /// "Publisher TC" segment
+    PublisherTc,
+}
+
+/// Location in the TCString at which decoding failed
+#[derive(PartialEq, Eq, Clone, PartialOrd, Hash, Debug, Default)]
+pub struct ErrorLocation {
+    /// Segment which was decoded, `None` if the failure isn't related to a single segment
+    pub segment: Option<SegmentType>,
+    /// Name of the field as defined in the specification, e.g. `MaxVendorId`
+    pub field: Option<&'static str>,
+    /// Bit offset of the field, relative to the start of the segment
+    pub bit: usize,
+}
+
+/// Errors that can occur while decoding or encoding the TCString
+///
+/// New variants can be added in minor releases, `match` expressions need a wildcard arm
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum TcsError {
     /// TCString doesn't have enough bits
     InsufficientLength,
-    /// TCString contains an invalid or unsupported version
-    UnsupportedVersion,
+    /// TCString contains an invalid or unsupported version, contains the version which was found
+    UnsupportedVersion(u8),
     /// TCString isn't valid base64
     InvalidUrlSafeBase64(DecodeError),
     /// TCString contains an invalid offset for string creation
@@ -30,24 +65,158 @@ pub enum TcsError {
     InvalidSegmentDefinition,
     /// TCString contains an unknown range section definition
     UnexpectedRangeSection,
+    /// TcModel contains a value which can't be represented in the TCString
+    ValueOutOfRange,
+    /// String doesn't match the expected format
+    InvalidFormat,
+    /// TCString contains a publisher restriction with the reserved restriction type `3` (strict decoding only)
+    InvalidRestrictionType,
+    /// TCString was created after it was last updated (strict decoding only)
+    InvalidTimestamps,
+    /// TCString contains a vendor range whose start is greater than its end (strict decoding only)
+    InvalidVendorRange,
+    /// TCString contains non-zero bits after the last field of a segment (strict decoding only)
+    NonZeroPadding,
+    /// TCString contains the same segment type more than once (strict decoding only)
+    DuplicateSegment,
+    /// TCString contains an unknown TCF policy version, contains the version which was found (strict decoding only)
+    UnsupportedPolicyVersion(u16),
+}
+
+/// Error which occurred while decoding a TCString, together with the location at which decoding failed
+///
+/// Returned by [`TcModelV2::decode_with_location`], converts into a [`TcsError`] by dropping the location
+///
+/// [`TcModelV2::decode_with_location`]: struct.TcModelV2.html#method.decode_with_location
+/// [`TcsError`]: enum.TcsError.html
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LocatedError {
+    /// Error which occurred
+    pub error: TcsError,
+    /// Location in the TCString, `None` if the error isn't caused by a single field (e.g. invalid base64)
+    pub location: Option<ErrorLocation>,
+}
+
+impl ErrorLocation {
+    pub(crate) fn new(field: &'static str, bit: usize) -> Self {
+        Self {
+            segment: None,
+            field: Some(field),
+            bit,
+        }
+    }
+}
+
+impl TcsError {
+    /// Returns the stable error code, e.g. `ERR_INSUFFICIENT_LENGTH`
+    pub fn code(&self) -> &'static str {
+        match self {
+            TcsError::InsufficientLength => INSUFFICIENT_LENGTH,
+            TcsError::UnsupportedVersion(_) => UNSUPPORTED_VERSION,
+            TcsError::InvalidUrlSafeBase64(_) => INVALID_URL_SAFE_BASE64,
+            TcsError::InvalidAlphabetOffset => INVALID_ALPHABET_OFFSET,
+            TcsError::InvalidSectionDefinition => INVALID_SECTION_DEFINITION,
+            TcsError::InvalidSegmentDefinition => INVALID_SEGMENT_DEFINITION,
+            TcsError::UnexpectedRangeSection => UNEXPECTED_RANGE_SECTION,
+            TcsError::ValueOutOfRange => VALUE_OUT_OF_RANGE,
+            TcsError::InvalidFormat => INVALID_FORMAT,
+            TcsError::InvalidRestrictionType => INVALID_RESTRICTION_TYPE,
+            TcsError::InvalidTimestamps => INVALID_TIMESTAMPS,
+            TcsError::InvalidVendorRange => INVALID_VENDOR_RANGE,
+            TcsError::NonZeroPadding => NON_ZERO_PADDING,
+            TcsError::DuplicateSegment => DUPLICATE_SEGMENT,
+            TcsError::UnsupportedPolicyVersion(_) => UNSUPPORTED_POLICY_VERSION,
+        }
+    }
+
+    pub(crate) fn at(self, location: ErrorLocation) -> LocatedError {
+        LocatedError {
+            error: self,
+            location: Some(location),
+        }
+    }
+}
+
+impl LocatedError {
+    pub(crate) fn in_segment(mut self, segment: SegmentType) -> Self {
+        if let Some(location) = &mut self.location {
+            location.segment.get_or_insert(segment);
+        }
+
+        self
+    }
+}
+
+impl From<TcsError> for LocatedError {
+    fn from(val: TcsError) -> Self {
+        Self {
+            error: val,
+            location: None,
+        }
+    }
+}
+
+impl From<LocatedError> for TcsError {
+    fn from(val: LocatedError) -> Self {
+        val.error
+    }
+}
+
+impl Display for SegmentType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SegmentType::Core => write!(f, "core"),
+            SegmentType::DisclosedVendors => write!(f, "disclosed vendors"),
+            SegmentType::AllowedVendors => write!(f, "allowed vendors"),
+            SegmentType::PublisherTc => write!(f, "publisher TC"),
+        }
+    }
+}
+
+impl Display for ErrorLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(segment) = self.segment {
+            write!(f, "segment {}, ", segment)?;
+        }
+
+        if let Some(field) = self.field {
+            write!(f, "field {}, ", field)?;
+        }
+
+        write!(f, "bit {}", self.bit)
+    }
 }
 
 impl Display for TcsError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            TcsError::InsufficientLength => write!(f, "{}", INSUFFICIENT_LENGTH),
-            TcsError::UnsupportedVersion => write!(f, "{}", UNSUPPORTED_VERSION),
+            TcsError::UnsupportedVersion(version) => write!(f, "{}: {}", self.code(), version),
             TcsError::InvalidUrlSafeBase64(decode_error) => {
-                write!(f, "{}: {}", INVALID_URL_SAFE_BASE64, decode_error)
+                write!(f, "{}: {}", self.code(), decode_error)
             }
-            TcsError::InvalidAlphabetOffset => write!(f, "{}", INVALID_ALPHABET_OFFSET),
-            TcsError::InvalidSectionDefinition => write!(f, "{}", INVALID_SECTION_DEFINITION),
-            TcsError::InvalidSegmentDefinition => write!(f, "{}", INVALID_SEGMENT_DEFINITION),
-            TcsError::UnexpectedRangeSection => write!(f, "{}", UNEXPECTED_RANGE_SECTION),
+            TcsError::UnsupportedPolicyVersion(tcf_policy_version) => {
+                write!(f, "{}: {}", self.code(), tcf_policy_version)
+            }
+            _ => write!(f, "{}", self.code()),
+        }
+    }
+}
+
+impl Display for LocatedError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "{} ({})", self.error, location),
+            None => write!(f, "{}", self.error),
         }
     }
 }
 
+impl Error for LocatedError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
 impl Error for TcsError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
@@ -56,3 +225,37 @@ impl Error for TcsError {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_display_keeps_code() {
+        let err = TcsError::InsufficientLength
+            .at(ErrorLocation::new("MaxVendorId", 213))
+            .in_segment(SegmentType::Core)
+            .in_segment(SegmentType::PublisherTc);
+
+        assert_eq!(err.error.code(), "ERR_INSUFFICIENT_LENGTH");
+        assert_eq!(
+            err.to_string(),
+            "ERR_INSUFFICIENT_LENGTH (segment core, field MaxVendorId, bit 213)"
+        );
+        assert_eq!(TcsError::from(err), TcsError::InsufficientLength);
+        assert_eq!(
+            TcsError::DuplicateSegment
+                .at(ErrorLocation::default())
+                .to_string(),
+            "ERR_DUPLICATE_SEGMENT (bit 0)"
+        );
+        assert_eq!(
+            LocatedError::from(TcsError::ValueOutOfRange).to_string(),
+            "ERR_VALUE_OUT_OF_RANGE"
+        );
+        assert_eq!(
+            TcsError::UnsupportedVersion(3).to_string(),
+            "ERR_UNSUPPORTED_VERSION: 3"
+        );
+    }
+}
@@ -0,0 +1,299 @@
+use std::convert::TryFrom;
+
+use base64::Engine;
+
+use crate::decode::{
+    error::{ErrorLocation, LocatedError, TcsError},
+    model::{GppSection, GppSectionError, GppString, TcModelV2},
+    util::{decode_segments, parse_from_bytes, BASE64_ENGINE},
+};
+
+const GPP_HEADER_TYPE: u64 = 3;
+const TCF_EU_V2_SECTION_ID: u16 = 2;
+
+fn decode_header(val: &str) -> Result<Vec<u8>, TcsError> {
+    // the header isn't byte aligned, padding it with zero bits keeps every bit of the last character
+    let mut padded_header = String::from(val);
+
+    while padded_header.len() % 4 != 0 {
+        padded_header.push('A');
+    }
+
+    BASE64_ENGINE
+        .decode(padded_header)
+        .map_err(TcsError::InvalidUrlSafeBase64)
+}
+
+fn parse_fibonacci_from_bytes(val: &[u8], bit_start: usize) -> Result<(u16, usize), LocatedError> {
+    let mut fibonacci = (1u64, 2u64);
+    let mut value = 0u64;
+    let mut bit_index = bit_start;
+    let mut previous_bit = false;
+
+    loop {
+        byte_list_bit_boundary_check!(
+            val,
+            bit_index + 1,
+            ErrorLocation::new("SectionIds", bit_index)
+        );
+
+        let bit = parse_from_bytes(val, bit_index, 1) == 1;
+
+        bit_index += 1;
+
+        if bit && previous_bit {
+            return Ok((value as u16, bit_index));
+        }
+
+        if bit {
+            value += fibonacci.0;
+        }
+
+        if value > u16::MAX as u64 || fibonacci.0 > u16::MAX as u64 {
+            return Err(
+                TcsError::InvalidSectionDefinition.at(ErrorLocation::new("SectionIds", bit_start))
+            );
+        }
+
+        fibonacci = (fibonacci.1, fibonacci.0 + fibonacci.1);
+        previous_bit = bit;
+    }
+}
+
+fn parse_fibonacci_range_from_bytes(
+    val: &[u8],
+    bit_start: usize,
+) -> Result<Vec<u16>, LocatedError> {
+    byte_list_bit_boundary_check!(
+        val,
+        bit_start + 12,
+        ErrorLocation::new("SectionIds", bit_start)
+    );
+
+    let num_entries = parse_from_bytes(val, bit_start, 12);
+    let mut entry_list: Vec<u16> = Vec::new();
+    let mut bit_index = bit_start + 12;
+    let mut offset = 0u16;
+
+    for _ in 0..num_entries {
+        byte_list_bit_boundary_check!(
+            val,
+            bit_index + 1,
+            ErrorLocation::new("SectionIds", bit_index)
+        );
+
+        let is_range = parse_from_bytes(val, bit_index, 1) == 1;
+        let (start, next_bit) = parse_fibonacci_from_bytes(val, bit_index + 1)?;
+        let start = offset.checked_add(start).ok_or_else(|| {
+            TcsError::InvalidSectionDefinition.at(ErrorLocation::new("SectionIds", bit_index + 1))
+        })?;
+
+        bit_index = next_bit;
+        offset = start;
+
+        if is_range {
+            let (end, next_bit) = parse_fibonacci_from_bytes(val, bit_index)?;
+            let end = start.checked_add(end).ok_or_else(|| {
+                TcsError::InvalidSectionDefinition.at(ErrorLocation::new("SectionIds", bit_index))
+            })?;
+
+            bit_index = next_bit;
+            offset = end;
+            entry_list.extend(start..=end);
+        } else {
+            entry_list.push(start);
+        }
+    }
+
+    Ok(entry_list)
+}
+
+fn parse_section(id: u16, val: &str) -> Result<GppSection, TcsError> {
+    Ok(match id {
+        TCF_EU_V2_SECTION_ID => GppSection::TcfEuV2(Box::new(TcModelV2::try_from(val)?)),
+        id => GppSection::Unknown {
+            id,
+            segments: decode_segments(val)?,
+        },
+    })
+}
+
+impl TryFrom<&str> for GppString {
+    type Error = TcsError;
+
+    fn try_from(val: &str) -> Result<Self, Self::Error> {
+        let mut parts = val.split('~');
+        let header = decode_header(parts.next().unwrap_or_default())?;
+
+        byte_list_bit_boundary_check!(header, 6, ErrorLocation::new("Type", 0));
+        byte_list_bit_boundary_check!(header, 12, ErrorLocation::new("Version", 6));
+
+        if parse_from_bytes(&header, 0, 6) != GPP_HEADER_TYPE {
+            return Err(TcsError::InvalidFormat);
+        }
+
+        let version = parse_from_bytes(&header, 6, 6) as u8;
+
+        if version != 1 {
+            return Err(TcsError::UnsupportedVersion(version));
+        }
+
+        let section_ids = parse_fibonacci_range_from_bytes(&header, 12)?;
+        let section_values: Vec<&str> = parts.collect();
+
+        if section_ids.len() != section_values.len() {
+            return Err(TcsError::InvalidFormat);
+        }
+
+        let mut sections = Vec::with_capacity(section_ids.len());
+        let mut section_errors = Vec::new();
+
+        for (section_id, section_value) in section_ids.iter().zip(section_values) {
+            match parse_section(*section_id, section_value) {
+                Ok(section) => sections.push(section),
+                Err(error) => section_errors.push(GppSectionError {
+                    id: *section_id,
+                    error,
+                }),
+            }
+        }
+
+        Ok(Self {
+            version,
+            section_ids,
+            sections,
+            section_errors,
+        })
+    }
+}
+
+impl GppString {
+    /// Returns the EU TCF v2 section if it is contained in the GPP string
+    pub fn tcf_eu_v2(&self) -> Option<&TcModelV2> {
+        self.sections.iter().find_map(|section| match section {
+            GppSection::TcfEuV2(tc_model) => Some(tc_model.as_ref()),
+            _ => None,
+        })
+    }
+}
+
+impl GppSection {
+    /// Returns the section ID
+    pub fn id(&self) -> u16 {
+        match self {
+            Self::TcfEuV2(_) => TCF_EU_V2_SECTION_ID,
+            Self::Unknown { id, segments: _ } => *id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fibonacci_range() {
+        // 2 entries: 3, range 5..=7
+        // 000000000010 0 0011 1 011 011
+        assert_eq!(
+            parse_fibonacci_range_from_bytes(&[0b0000_0000, 0b0010_0001, 0b1101_1011], 0),
+            Ok(vec![3, 5, 6, 7])
+        );
+    }
+
+    #[test]
+    fn gpp_tcf_eu_v2_section() {
+        let gpp_string =
+            GppString::try_from("DBABM~CPXxRfAPXxRfAAfKABENB-CgAAAAAAAAAAYgAAAAAAAA").unwrap();
+
+        assert_eq!(gpp_string.version, 1);
+        assert_eq!(gpp_string.section_ids, vec![2]);
+        assert_eq!(
+            gpp_string.tcf_eu_v2(),
+            TcModelV2::try_from("CPXxRfAPXxRfAAfKABENB-CgAAAAAAAAAAYgAAAAAAAA")
+                .ok()
+                .as_ref()
+        );
+    }
+
+    #[test]
+    fn gpp_unknown_sections() {
+        let gpp_string =
+            GppString::try_from("DBACNYA~CPXxRfAPXxRfAAfKABENB-CgAAAAAAAAAAYgAAAAAAAA~1YNN")
+                .unwrap();
+
+        assert_eq!(gpp_string.section_ids, vec![2, 6]);
+        assert_eq!(
+            gpp_string
+                .sections
+                .iter()
+                .map(GppSection::id)
+                .collect::<Vec<u16>>(),
+            vec![2, 6]
+        );
+        assert_eq!(
+            gpp_string.sections[1],
+            GppSection::Unknown {
+                id: 6,
+                segments: vec![vec![213, 131, 77]],
+            }
+        );
+    }
+
+    #[test]
+    fn gpp_unknown_section_segments() {
+        let gpp_string = GppString::try_from("DBABrw~BVVqAAEABCA.QA~BVoYYZoI").unwrap();
+
+        assert_eq!(gpp_string.section_ids, vec![7, 8]);
+        assert_eq!(
+            gpp_string.sections,
+            vec![
+                GppSection::Unknown {
+                    id: 7,
+                    segments: vec![vec![5, 85, 106, 0, 1, 0, 4, 32], vec![64]],
+                },
+                GppSection::Unknown {
+                    id: 8,
+                    segments: vec![vec![5, 90, 24, 97, 154, 8]],
+                },
+            ]
+        );
+        assert!(gpp_string.section_errors.is_empty());
+    }
+
+    #[test]
+    fn gpp_invalid_section() {
+        let gpp_string = GppString::try_from("DBACNYA~CPXxRfAPXxRf~1YNN").unwrap();
+
+        assert_eq!(gpp_string.section_ids, vec![2, 6]);
+        assert_eq!(gpp_string.tcf_eu_v2(), None);
+        assert_eq!(
+            gpp_string.sections,
+            vec![GppSection::Unknown {
+                id: 6,
+                segments: vec![vec![213, 131, 77]],
+            }]
+        );
+        assert_eq!(
+            gpp_string
+                .section_errors
+                .iter()
+                .map(|section_error| (section_error.id, section_error.error.code()))
+                .collect::<Vec<(u16, &str)>>(),
+            vec![(2, "ERR_INSUFFICIENT_LENGTH")]
+        );
+    }
+
+    #[test]
+    fn gpp_invalid() {
+        assert_eq!(GppString::try_from("DBABM"), Err(TcsError::InvalidFormat));
+        assert_eq!(
+            GppString::try_from("CBABM~CPXxRfAPXxRfAAfKABENB-CgAAAAAAAAAAYgAAAAAAAA"),
+            Err(TcsError::InvalidFormat)
+        );
+        assert_eq!(
+            GppString::try_from("DCABM~CPXxRfAPXxRfAAfKABENB-CgAAAAAAAAAAYgAAAAAAAA"),
+            Err(TcsError::UnsupportedVersion(2))
+        );
+    }
+}
@@ -0,0 +1,283 @@
+use std::{
+    convert::TryFrom,
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+use crate::decode::{
+    error::TcsError,
+    model::{CountryCode, LanguageCode, TcModelV1, TcModelV2},
+};
+
+/// ISO 639-1 language codes, sorted for binary search
+const ISO_639_1: [&str; 183] = [
+    "AA", "AB", "AE", "AF", "AK", "AM", "AN", "AR", "AS", "AV", "AY", "AZ", "BA", "BE", "BG", "BI",
+    "BM", "BN", "BO", "BR", "BS", "CA", "CE", "CH", "CO", "CR", "CS", "CU", "CV", "CY", "DA", "DE",
+    "DV", "DZ", "EE", "EL", "EN", "EO", "ES", "ET", "EU", "FA", "FF", "FI", "FJ", "FO", "FR", "FY",
+    "GA", "GD", "GL", "GN", "GU", "GV", "HA", "HE", "HI", "HO", "HR", "HT", "HU", "HY", "HZ", "IA",
+    "ID", "IE", "IG", "II", "IK", "IO", "IS", "IT", "IU", "JA", "JV", "KA", "KG", "KI", "KJ", "KK",
+    "KL", "KM", "KN", "KO", "KR", "KS", "KU", "KV", "KW", "KY", "LA", "LB", "LG", "LI", "LN", "LO",
+    "LT", "LU", "LV", "MG", "MH", "MI", "MK", "ML", "MN", "MR", "MS", "MT", "MY", "NA", "NB", "ND",
+    "NE", "NG", "NL", "NN", "NO", "NR", "NV", "NY", "OC", "OJ", "OM", "OR", "OS", "PA", "PI", "PL",
+    "PS", "PT", "QU", "RM", "RN", "RO", "RU", "RW", "SA", "SC", "SD", "SE", "SG", "SI", "SK", "SL",
+    "SM", "SN", "SO", "SQ", "SR", "SS", "ST", "SU", "SV", "SW", "TA", "TE", "TG", "TH", "TI", "TK",
+    "TL", "TN", "TO", "TR", "TS", "TT", "TW", "TY", "UG", "UK", "UR", "UZ", "VE", "VI", "VO", "WA",
+    "WO", "XH", "YI", "YO", "ZA", "ZH", "ZU",
+];
+
+/// ISO 3166-1 alpha-2 country codes and the TCF placeholder `AA`, sorted for binary search
+const ISO_3166_1_ALPHA_2: [&str; 250] = [
+    "AA", "AD", "AE", "AF", "AG", "AI", "AL", "AM", "AO", "AQ", "AR", "AS", "AT", "AU", "AW", "AX",
+    "AZ", "BA", "BB", "BD", "BE", "BF", "BG", "BH", "BI", "BJ", "BL", "BM", "BN", "BO", "BQ", "BR",
+    "BS", "BT", "BV", "BW", "BY", "BZ", "CA", "CC", "CD", "CF", "CG", "CH", "CI", "CK", "CL", "CM",
+    "CN", "CO", "CR", "CU", "CV", "CW", "CX", "CY", "CZ", "DE", "DJ", "DK", "DM", "DO", "DZ", "EC",
+    "EE", "EG", "EH", "ER", "ES", "ET", "FI", "FJ", "FK", "FM", "FO", "FR", "GA", "GB", "GD", "GE",
+    "GF", "GG", "GH", "GI", "GL", "GM", "GN", "GP", "GQ", "GR", "GS", "GT", "GU", "GW", "GY", "HK",
+    "HM", "HN", "HR", "HT", "HU", "ID", "IE", "IL", "IM", "IN", "IO", "IQ", "IR", "IS", "IT", "JE",
+    "JM", "JO", "JP", "KE", "KG", "KH", "KI", "KM", "KN", "KP", "KR", "KW", "KY", "KZ", "LA", "LB",
+    "LC", "LI", "LK", "LR", "LS", "LT", "LU", "LV", "LY", "MA", "MC", "MD", "ME", "MF", "MG", "MH",
+    "MK", "ML", "MM", "MN", "MO", "MP", "MQ", "MR", "MS", "MT", "MU", "MV", "MW", "MX", "MY", "MZ",
+    "NA", "NC", "NE", "NF", "NG", "NI", "NL", "NO", "NP", "NR", "NU", "NZ", "OM", "PA", "PE", "PF",
+    "PG", "PH", "PK", "PL", "PM", "PN", "PR", "PS", "PT", "PW", "PY", "QA", "RE", "RO", "RS", "RU",
+    "RW", "SA", "SB", "SC", "SD", "SE", "SG", "SH", "SI", "SJ", "SK", "SL", "SM", "SN", "SO", "SR",
+    "SS", "ST", "SV", "SX", "SY", "SZ", "TC", "TD", "TF", "TG", "TH", "TJ", "TK", "TL", "TM", "TN",
+    "TO", "TR", "TT", "TV", "TW", "TZ", "UA", "UG", "UM", "US", "UY", "UZ", "VA", "VC", "VE", "VG",
+    "VI", "VN", "VU", "WF", "WS", "YE", "YT", "ZA", "ZM", "ZW",
+];
+
+fn parse_letters(val: &str, char_count: usize) -> Result<String, TcsError> {
+    if val.len() != char_count || !val.chars().all(|character| character.is_ascii_alphabetic()) {
+        return Err(TcsError::InvalidFormat);
+    }
+
+    Ok(val.to_ascii_uppercase())
+}
+
+fn parse_code(val: &str, codes: &[&str]) -> Result<String, TcsError> {
+    let code = parse_letters(val, 2)?;
+
+    if codes.binary_search(&code.as_str()).is_ok() {
+        Ok(code)
+    } else {
+        Err(TcsError::InvalidFormat)
+    }
+}
+
+impl LanguageCode {
+    /// Returns the normalized (uppercase) language code, e.g. `EN` or `SR-LATN`
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the two letter [`ISO 639-1`] language which is encoded into a TCString
+    ///
+    /// [`ISO 639-1`]: https://en.wikipedia.org/wiki/ISO_639-1
+    pub fn language(&self) -> &str {
+        &self.0[..2]
+    }
+
+    /// Returns the four letter script subtag of an extended language code, e.g. `LATN` for `SR-LATN`
+    pub fn script(&self) -> Option<&str> {
+        self.0.get(3..)
+    }
+}
+
+impl TryFrom<&str> for LanguageCode {
+    type Error = TcsError;
+
+    fn try_from(val: &str) -> Result<Self, Self::Error> {
+        let mut subtags = val.splitn(2, '-');
+        let language = parse_code(subtags.next().unwrap_or_default(), &ISO_639_1)?;
+
+        match subtags.next() {
+            Some(script) => Ok(Self(format!("{}-{}", language, parse_letters(script, 4)?))),
+            None => Ok(Self(language)),
+        }
+    }
+}
+
+impl TryFrom<String> for LanguageCode {
+    type Error = TcsError;
+
+    fn try_from(val: String) -> Result<Self, Self::Error> {
+        Self::try_from(val.as_str())
+    }
+}
+
+impl From<LanguageCode> for String {
+    fn from(val: LanguageCode) -> Self {
+        val.0
+    }
+}
+
+impl FromStr for LanguageCode {
+    type Err = TcsError;
+
+    fn from_str(val: &str) -> Result<Self, Self::Err> {
+        Self::try_from(val)
+    }
+}
+
+impl Display for LanguageCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl AsRef<str> for LanguageCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl CountryCode {
+    /// Returns the normalized (uppercase) country code, e.g. `DE`
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<&str> for CountryCode {
+    type Error = TcsError;
+
+    fn try_from(val: &str) -> Result<Self, Self::Error> {
+        Ok(Self(parse_code(val, &ISO_3166_1_ALPHA_2)?))
+    }
+}
+
+impl TryFrom<String> for CountryCode {
+    type Error = TcsError;
+
+    fn try_from(val: String) -> Result<Self, Self::Error> {
+        Self::try_from(val.as_str())
+    }
+}
+
+impl From<CountryCode> for String {
+    fn from(val: CountryCode) -> Self {
+        val.0
+    }
+}
+
+impl FromStr for CountryCode {
+    type Err = TcsError;
+
+    fn from_str(val: &str) -> Result<Self, Self::Err> {
+        Self::try_from(val)
+    }
+}
+
+impl Display for CountryCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl AsRef<str> for CountryCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl TcModelV1 {
+    /// Returns the [`consent_language`] as [`LanguageCode`], `None` if it isn't a valid ISO 639-1 language code
+    ///
+    /// [`consent_language`]: struct.TcModelV1.html#structfield.consent_language
+    /// [`LanguageCode`]: struct.LanguageCode.html
+    pub fn consent_language_code(&self) -> Option<LanguageCode> {
+        LanguageCode::try_from(self.consent_language.as_str()).ok()
+    }
+}
+
+impl TcModelV2 {
+    /// Returns the [`consent_language`] as [`LanguageCode`], `None` if it isn't a valid ISO 639-1 language code
+    ///
+    /// [`consent_language`]: struct.TcModelV2.html#structfield.consent_language
+    /// [`LanguageCode`]: struct.LanguageCode.html
+    pub fn consent_language_code(&self) -> Option<LanguageCode> {
+        LanguageCode::try_from(self.consent_language.as_str()).ok()
+    }
+
+    /// Returns the [`publisher_country_code`] as [`CountryCode`], `None` if it isn't a valid ISO 3166-1 alpha-2 country code
+    ///
+    /// [`publisher_country_code`]: struct.TcModelV2.html#structfield.publisher_country_code
+    /// [`CountryCode`]: struct.CountryCode.html
+    pub fn publisher_country(&self) -> Option<CountryCode> {
+        CountryCode::try_from(self.publisher_country_code.as_str()).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iso_code_tables_are_sorted() {
+        assert!(ISO_639_1.windows(2).all(|codes| codes[0] < codes[1]));
+        assert!(ISO_3166_1_ALPHA_2
+            .windows(2)
+            .all(|codes| codes[0] < codes[1]));
+    }
+
+    #[test]
+    fn language_code() {
+        let language_code = LanguageCode::try_from("en").unwrap();
+
+        assert_eq!(language_code.as_str(), "EN");
+        assert_eq!(language_code.language(), "EN");
+        assert_eq!(language_code.script(), None);
+
+        let language_code: LanguageCode = "sr-Cyrl".parse().unwrap();
+
+        assert_eq!(language_code.to_string(), "SR-CYRL");
+        assert_eq!(language_code.language(), "SR");
+        assert_eq!(language_code.script(), Some("CYRL"));
+
+        assert_eq!(LanguageCode::try_from("XX"), Err(TcsError::InvalidFormat));
+        assert_eq!(LanguageCode::try_from("ENG"), Err(TcsError::InvalidFormat));
+        assert_eq!(
+            LanguageCode::try_from("SR-LAT"),
+            Err(TcsError::InvalidFormat)
+        );
+        assert_eq!(LanguageCode::try_from("SR-"), Err(TcsError::InvalidFormat));
+        assert_eq!(LanguageCode::try_from(""), Err(TcsError::InvalidFormat));
+    }
+
+    #[test]
+    fn country_code() {
+        assert_eq!(CountryCode::try_from("de").unwrap().as_str(), "DE");
+        assert_eq!(CountryCode::try_from("AA").unwrap().as_str(), "AA");
+        assert_eq!(CountryCode::try_from("HL"), Err(TcsError::InvalidFormat));
+        assert_eq!(CountryCode::try_from("D1"), Err(TcsError::InvalidFormat));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn iso_codes_serde() {
+        assert_eq!(
+            serde_json::from_str::<LanguageCode>(r#""sr-Latn""#).unwrap(),
+            LanguageCode::try_from("SR-LATN").unwrap()
+        );
+        assert_eq!(
+            serde_json::to_string(&CountryCode::try_from("de").unwrap()).unwrap(),
+            r#""DE""#
+        );
+        assert!(serde_json::from_str::<LanguageCode>(r#""XX""#).is_err());
+        assert!(serde_json::from_str::<CountryCode>(r#""HL""#).is_err());
+    }
+
+    #[test]
+    fn tc_model_iso_codes() {
+        let tc_model = TcModelV2 {
+            consent_language: String::from("EN"),
+            publisher_country_code: String::from("HL"),
+            ..TcModelV2::default()
+        };
+
+        assert_eq!(
+            tc_model.consent_language_code(),
+            LanguageCode::try_from("EN").ok()
+        );
+        assert_eq!(tc_model.publisher_country(), None);
+    }
+}
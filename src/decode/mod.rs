@@ -1,4 +1,19 @@
+pub mod additional_consent;
+pub mod batch;
 pub mod error;
+#[cfg(test)]
+pub(crate) mod fixtures;
+pub mod gpp;
+pub mod iso_code;
 pub mod model;
+pub mod policy_version;
+pub mod publisher_restrictions;
+pub mod purpose;
+pub mod tc_model;
+pub mod tcf_1;
 pub mod tcf_2;
+pub mod timestamp;
+pub mod us_privacy;
 pub(crate) mod util;
+pub mod vendor_set;
+pub mod view;
@@ -1,8 +1,12 @@
+use std::collections::BTreeMap;
+
+use crate::decode::error::TcsError;
+
 /// Contains restriction types as defined in [`Vendor Consent String Format V2 Core String`]
 ///
 /// [`Vendor Consent String Format V2 Core String`]: https://github.com/InteractiveAdvertisingBureau/GDPR-Transparency-and-Consent-Framework/blob/81a3b9ed1545148be380b4408e6361cd2294446d/TCFv2/IAB%20Tech%20Lab%20-%20Consent%20string%20and%20vendor%20list%20formats%20v2.md#the-core-string
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(PartialEq, Eq, Clone, PartialOrd, Hash, Debug)]
+#[derive(PartialEq, Eq, Clone, PartialOrd, Hash, Debug, Default)]
 pub enum PublisherRestrictionType {
     /// Purpose Flatly Not Allowed by Publisher
     NotAllowed,
@@ -11,9 +15,296 @@ pub enum PublisherRestrictionType {
     /// Specifies that vendors need to have "Legitimate Interest"
     RequireLegitimateInterest,
     /// Should not be used
+    #[default]
     Undefined,
 }
 
+/// TCF policy version of a TCString, see [`TcModelV2::policy_version`]
+///
+/// Versions are compared by their numeric value, `Unknown(1) < V2` and `Unknown(4) == V4`
+///
+/// [`TcModelV2::policy_version`]: struct.TcModelV2.html#method.policy_version
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub enum TcfPolicyVersion {
+    /// Policy version 2 (TCF v2.0)
+    V2,
+    /// Policy version 3 (TCF v2.0 with the 2021 policy update)
+    V3,
+    /// Policy version 4 (TCF v2.2), "Legitimate Interest" isn't allowed for purposes 3 to 6
+    V4,
+    /// Policy version 5 (TCF v2.2 with the 2024 policy update)
+    V5,
+    /// Policy version which isn't known by this library
+    Unknown(u16),
+}
+
+/// TCF v2 purpose as defined in the [`Policies`] (Appendix A)
+///
+/// ```rust,edition2021
+/// use lib_tcstring::Purpose;
+///
+/// assert_eq!(Purpose::from(7), Purpose::MeasureAdPerformance);
+/// assert_eq!(u8::from(Purpose::StoreAccessInformation), 1);
+/// assert_eq!(Purpose::Unknown(7), Purpose::MeasureAdPerformance);
+/// ```
+///
+/// Purposes are compared by their ID
+///
+/// [`Policies`]: https://iabeurope.eu/iab-europe-transparency-consent-framework-policies/
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub enum Purpose {
+    /// Purpose 1, store and/or access information on a device
+    StoreAccessInformation,
+    /// Purpose 2, use limited data to select advertising ("Select basic ads" before TCF v2.2)
+    SelectBasicAds,
+    /// Purpose 3, create profiles for personalised advertising
+    CreatePersonalisedAdsProfile,
+    /// Purpose 4, use profiles to select personalised advertising
+    SelectPersonalisedAds,
+    /// Purpose 5, create profiles to personalise content
+    CreatePersonalisedContentProfile,
+    /// Purpose 6, use profiles to select personalised content
+    SelectPersonalisedContent,
+    /// Purpose 7, measure advertising performance
+    MeasureAdPerformance,
+    /// Purpose 8, measure content performance
+    MeasureContentPerformance,
+    /// Purpose 9, understand audiences through statistics or combinations of data from different sources
+    MarketResearch,
+    /// Purpose 10, develop and improve services
+    DevelopImproveProducts,
+    /// Purpose 11, use limited data to select content (added in TCF v2.2)
+    SelectBasicContent,
+    /// Purpose ID which isn't defined by the TCF
+    Unknown(u8),
+}
+
+/// TCF v2 special feature as defined in the [`Policies`] (Appendix A)
+///
+/// Special features are compared by their ID
+///
+/// [`Policies`]: https://iabeurope.eu/iab-europe-transparency-consent-framework-policies/
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub enum SpecialFeature {
+    /// Special feature 1, use precise geolocation data
+    UsePreciseGeolocation,
+    /// Special feature 2, actively scan device characteristics for identification
+    ActivelyScanDeviceCharacteristics,
+    /// Special feature ID which isn't defined by the TCF
+    Unknown(u8),
+}
+
+/// Options which are used by [`TcModelV2::decode_with_options`]
+///
+/// The default decoding is lenient and accepts TC Strings which violate the specification,
+/// as long as all fields can be read
+///
+/// [`TcModelV2::decode_with_options`]: struct.TcModelV2.html#method.decode_with_options
+#[derive(PartialEq, Eq, Clone, PartialOrd, Hash, Debug, Default)]
+pub struct DecodeOptions {
+    /// Rejects TC Strings which violate the specification with a specific [`TcsError`]
+    ///
+    /// * [`TcsError::InvalidRestrictionType`] for the reserved publisher restriction type `3`
+    /// * [`TcsError::InvalidTimestamps`] if `Created` is after `LastUpdated`
+    /// * [`TcsError::InvalidVendorRange`] for vendor ranges whose start is greater than their end
+    /// * [`TcsError::NonZeroPadding`] for non-zero bits after the last field of a segment
+    /// * [`TcsError::DuplicateSegment`] if a segment type occurs more than once
+    /// * [`TcsError::UnsupportedPolicyVersion`] for unknown `TcfPolicyVersion` values
+    ///
+    /// [`TcsError`]: enum.TcsError.html
+    /// [`TcsError::InvalidRestrictionType`]: enum.TcsError.html#variant.InvalidRestrictionType
+    /// [`TcsError::InvalidTimestamps`]: enum.TcsError.html#variant.InvalidTimestamps
+    /// [`TcsError::InvalidVendorRange`]: enum.TcsError.html#variant.InvalidVendorRange
+    /// [`TcsError::NonZeroPadding`]: enum.TcsError.html#variant.NonZeroPadding
+    /// [`TcsError::DuplicateSegment`]: enum.TcsError.html#variant.DuplicateSegment
+    /// [`TcsError::UnsupportedPolicyVersion`]: enum.TcsError.html#variant.UnsupportedPolicyVersion
+    pub strict: bool,
+}
+
+/// `VendorSet` is a compact set of vendor IDs backed by a bitset
+///
+/// Membership checks are `O(1)` and the memory usage is bounded by the highest contained vendor ID
+/// (at most 8 KiB), iteration returns the vendor IDs in ascending order
+///
+/// ```rust,edition2021
+/// use lib_tcstring::VendorSet;
+/// let mut vendor_set: VendorSet = vec![2, 6, 8].into();
+///
+/// vendor_set.insert_range(10..=20);
+///
+/// assert!(vendor_set.contains(6));
+/// assert!(!vendor_set.contains(7));
+/// assert_eq!(vendor_set.len(), 14);
+/// ```
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "Vec<u16>", into = "Vec<u16>")
+)]
+#[derive(PartialEq, Eq, Clone, Hash, Default)]
+pub struct VendorSet {
+    pub(crate) words: Vec<u64>,
+    pub(crate) len: usize,
+}
+
+/// Iterator over the vendor IDs of a [`VendorSet`] in ascending order
+///
+/// [`VendorSet`]: struct.VendorSet.html
+#[derive(Clone, Debug)]
+pub struct VendorSetIter<'a> {
+    pub(crate) words: &'a [u64],
+    pub(crate) word_index: usize,
+    pub(crate) word: u64,
+}
+
+/// Borrowed view of a TCF v2 TCString which reads fields on demand
+///
+/// Only the core segment is decoded (into an inline buffer for typical lengths) and its structure is validated once,
+/// the accessors read the requested bits without allocating.
+/// Use [`to_model`] to decode every field and segment into a [`TcModelV2`].
+///
+/// ```rust,edition2021
+/// use std::convert::TryFrom;
+/// use lib_tcstring::TcStringView;
+/// let tc_view = TcStringView::try_from("COvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAAAEEUACCKAAA").unwrap();
+///
+/// assert!(tc_view.has_vendor_consent(8));
+/// assert!(tc_view.purpose_consent(1));
+/// ```
+///
+/// [`to_model`]: struct.TcStringView.html#method.to_model
+/// [`TcModelV2`]: struct.TcModelV2.html
+#[derive(Clone, Debug)]
+pub struct TcStringView<'a> {
+    pub(crate) tc_string: &'a str,
+    pub(crate) core_segment: SegmentBuffer,
+    pub(crate) vendors_consent: VendorSectionView,
+    pub(crate) vendors_li_consent: VendorSectionView,
+}
+
+/// Decodes many TCF v2 TCStrings while reusing the buffers of the decoded segments between calls
+///
+/// Enable the `rayon` feature to decode a slice of TCStrings in parallel with [`par_decode`].
+///
+/// ```rust,edition2021
+/// use lib_tcstring::{BatchDecoder, DecodeOptions};
+/// let mut decoder = BatchDecoder::new(DecodeOptions::default());
+/// let tc_strings = [
+///     "COvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAAAEEUACCKAAA",
+///     "BOEFEAyOEFEAyAHABDENAI4AAAB9vABAASA",
+/// ];
+/// let results: Vec<_> = decoder.decode_iter(tc_strings).collect();
+///
+/// assert!(results[0].is_ok());
+/// assert!(results[1].is_err());
+/// ```
+///
+/// [`par_decode`]: struct.BatchDecoder.html#method.par_decode
+#[derive(Clone, Debug, Default)]
+pub struct BatchDecoder {
+    pub(crate) options: DecodeOptions,
+    pub(crate) tcs_segments: Vec<Vec<u8>>,
+}
+
+/// Iterator which decodes the TCStrings of another iterator, see [`BatchDecoder::decode_iter`]
+///
+/// [`BatchDecoder::decode_iter`]: struct.BatchDecoder.html#method.decode_iter
+#[derive(Debug)]
+pub struct BatchDecodeIter<'a, I> {
+    pub(crate) decoder: &'a mut BatchDecoder,
+    pub(crate) iter: I,
+}
+
+/// `TcModelV1` contains all relevant fields specified in the [`Vendor Consent String Format V1.1`]
+/// except for the `Version` field which is omitted
+///
+/// Field mapping
+/// * `Created` -> [`created_at`]
+/// * `LastUpdated` -> [`updated_at`]
+/// * `CmpId` -> [`cmp_id`]
+/// * `CmpVersion` -> [`cmp_version`]
+/// * `ConsentScreen` -> [`consent_screen`]
+/// * `ConsentLanguage` -> [`consent_language`]
+/// * `VendorListVersion` -> [`vendor_list_version`]
+/// * `PurposesAllowed` -> [`purposes_consent`]
+/// * `BitField` or `DefaultConsent` and `RangeEntry` -> [`vendors_consent`]
+///
+/// ```rust,edition2021
+/// use std::convert::TryFrom;
+/// // will return a Result which contains either the TcModel or an Error
+/// // if the TCString could not be parsed or the TCString includes an unsupported version
+/// let tc_model = lib_tcstring::TcModelV1::try_from("BOEFEAyOEFEAyAHABDENAI4AAAB9vABAASA");
+/// ```
+///
+/// [`created_at`]: struct.TcModelV1.html#structfield.created_at
+/// [`updated_at`]: struct.TcModelV1.html#structfield.updated_at
+/// [`cmp_id`]: struct.TcModelV1.html#structfield.cmp_id
+/// [`cmp_version`]: struct.TcModelV1.html#structfield.cmp_version
+/// [`consent_screen`]: struct.TcModelV1.html#structfield.consent_screen
+/// [`consent_language`]: struct.TcModelV1.html#structfield.consent_language
+/// [`vendor_list_version`]: struct.TcModelV1.html#structfield.vendor_list_version
+/// [`purposes_consent`]: struct.TcModelV1.html#structfield.purposes_consent
+/// [`vendors_consent`]: struct.TcModelV1.html#structfield.vendors_consent
+/// [`Vendor Consent String Format V1.1`]: https://github.com/InteractiveAdvertisingBureau/GDPR-Transparency-and-Consent-Framework/blob/81a3b9ed1545148be380b4408e6361cd2294446d/Consent%20string%20and%20vendor%20list%20formats%20v1.1%20Final.md#vendor-consent-string-format-
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Clone, PartialOrd, Hash, Debug, Default)]
+pub struct TcModelV1 {
+    /// Epoch milliseconds when this TC String was first created
+    pub created_at: u64,
+    /// Epoch milliseconds when TC String was last updated
+    pub updated_at: u64,
+    /// Consent Management Platform ID that last updated the TC String
+    pub cmp_id: u16,
+    /// Consent Management Platform version of the CMP that last updated this TC String
+    pub cmp_version: u16,
+    /// CMP Screen number at which consent was given for a user with the CMP that last updated this TC String
+    pub consent_screen: u8,
+    /// [`ISO 639-1`] language code in which the CMP UI was presented
+    ///
+    /// [`ISO 639-1`]: https://en.wikipedia.org/wiki/ISO_639-1
+    pub consent_language: String,
+    /// Version of the global vendor list used to create this TC String
+    pub vendor_list_version: u16,
+    /// List of allowed purposes
+    pub purposes_consent: Vec<u8>,
+    /// List of allowed vendors
+    ///
+    /// The `DefaultConsent` of a range encoded TC String is already applied
+    pub vendors_consent: VendorSet,
+}
+
+/// `TcModel` contains the model matching the version of a decoded TC String
+///
+/// New variants are added for future TC String versions, so matching requires a wildcard arm
+///
+/// ```rust,edition2021
+/// use std::convert::TryFrom;
+/// use lib_tcstring::TcModel;
+/// // the version field of the TC String decides which model is returned
+/// match TcModel::try_from("COvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAAAEEUACCKAAA") {
+///     Ok(TcModel::V1(tc_model_v1)) => println!("{:?}", tc_model_v1),
+///     Ok(TcModel::V2(tc_model_v2)) => println!("{:?}", tc_model_v2),
+///     Ok(tc_model) => println!("version {} is not handled", tc_model.version()),
+///     Err(err) => println!("{}", err),
+/// }
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Clone, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum TcModel {
+    /// TC String with version 1, see [`TcModelV1`]
+    ///
+    /// [`TcModelV1`]: struct.TcModelV1.html
+    V1(TcModelV1),
+    /// TC String with version 2, see [`TcModelV2`]
+    ///
+    /// [`TcModelV2`]: struct.TcModelV2.html
+    V2(Box<TcModelV2>),
+}
+
 /// `TcModelV2` contains all relevant fields specified in the [`Vendor Consent String Format V2`]
 /// except for the `Version` field which is omitted
 ///
@@ -128,19 +419,19 @@ pub struct TcModelV2 {
     /// [`ISO 3166-1 alpha-2`]: https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2
     pub publisher_country_code: String,
     /// List of allowed vendors
-    pub vendors_consent: Vec<u16>,
+    pub vendors_consent: VendorSet,
     /// List of vendors "Legitimate Interest" disclosures
-    pub vendors_li_consent: Vec<u16>,
+    pub vendors_li_consent: VendorSet,
     /// List of publisher restrictions on a per-purpose basis
     ///
     /// See [`PublisherRestriction`] for more details
     ///
     /// [`PublisherRestriction`]: struct.PublisherRestriction.html
-    pub publisher_restrictions: Vec<PublisherRestriction>,
+    pub publisher_restrictions: PublisherRestrictions,
     /// List of vendors that have been disclosed to a given user by a CMP
-    pub disclosed_vendors: Vec<u16>,
+    pub disclosed_vendors: VendorSet,
     /// List of vendors the publisher permits using OOB legal bases
-    pub allowed_vendors: Vec<u16>,
+    pub allowed_vendors: VendorSet,
     /// List of purposes which are established on the legal basis of consent, for the publisher
     pub publisher_purposes_consent: Vec<u8>,
     /// List of purposes which are established on the legal basis of "Legitimate Interest" and the user has not exercised their “Right to Object”
@@ -162,20 +453,243 @@ pub struct PublisherRestriction {
     /// [`PublisherRestrictionType`]: enum.PublisherRestrictionType.html
     pub restriction_type: PublisherRestrictionType,
     /// List of relevant vendors
-    pub vendor_list: Vec<u16>,
+    pub vendor_list: VendorSet,
+}
+
+/// `PublisherRestrictions` contains the publisher restrictions of a TCString in their original order,
+/// indexed by purpose to look up the restriction which applies to a vendor
+///
+/// ```rust,edition2021
+/// use lib_tcstring::{PublisherRestriction, PublisherRestrictionType, PublisherRestrictions};
+/// let publisher_restrictions: PublisherRestrictions = vec![PublisherRestriction {
+///     purpose_id: 2,
+///     restriction_type: PublisherRestrictionType::RequireConsent,
+///     vendor_list: vec![8, 755].into(),
+/// }]
+/// .into();
+///
+/// assert_eq!(
+///     publisher_restrictions.restriction_for(2, 755),
+///     Some(PublisherRestrictionType::RequireConsent)
+/// );
+/// assert_eq!(publisher_restrictions.restriction_for(3, 755), None);
+/// ```
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "Vec<PublisherRestriction>", into = "Vec<PublisherRestriction>")
+)]
+#[derive(PartialEq, Eq, Clone, PartialOrd, Hash, Default)]
+pub struct PublisherRestrictions {
+    pub(crate) entries: Vec<PublisherRestriction>,
+    pub(crate) by_purpose: BTreeMap<u8, Vec<usize>>,
+}
+
+/// Decoded non-core segment of a TCF v2 TCString
+///
+/// Every variant matches a segment type of the [`Vendor Consent String Format V2`]
+///
+/// ```rust,edition2021
+/// use std::convert::TryFrom;
+/// use lib_tcstring::Segment;
+/// // disclosed vendors segment of a TCString
+/// let segment = Segment::try_from("IAPPwAPrwA").unwrap();
+///
+/// assert!(matches!(segment, Segment::DisclosedVendors(_)));
+/// ```
+///
+/// [`Vendor Consent String Format V2`]: https://github.com/InteractiveAdvertisingBureau/GDPR-Transparency-and-Consent-Framework/blob/81a3b9ed1545148be380b4408e6361cd2294446d/TCFv2/IAB%20Tech%20Lab%20-%20Consent%20string%20and%20vendor%20list%20formats%20v2.md#tc-string-format
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Clone, PartialOrd, Hash, Debug)]
+pub enum Segment {
+    /// Vendors which were disclosed to the user
+    DisclosedVendors(VendorSet),
+    /// Vendors which the publisher permits to use OOB legal bases
+    AllowedVendors(VendorSet),
+    /// Publisher purposes and custom purposes
+    PublisherTc(PublisherTc),
+}
+
+/// `AdditionalConsent` contains the Google Additional Consent Mode string (`addtl_consent`) as specified in the
+/// [`Additional Consent Mode technical specification`]
+///
+/// Version 1 (`1~1.35.41`) only contains consented providers, version 2 (`2~1.35.41~dv.9.21`) also contains
+/// the providers which were disclosed to the user
+///
+/// ```rust,edition2021
+/// use std::convert::TryFrom;
+/// // will return a Result which contains either the AdditionalConsent or an Error
+/// // if the string could not be parsed or includes an unsupported version
+/// let additional_consent = lib_tcstring::AdditionalConsent::try_from("2~1.35.41~dv.9.21");
+/// ```
+///
+/// [`Additional Consent Mode technical specification`]: https://support.google.com/admanager/answer/9681920
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Clone, PartialOrd, Hash, Debug, Default)]
+pub struct AdditionalConsent {
+    /// Version of the Additional Consent string
+    pub version: u8,
+    /// List of Google Ad Tech Provider IDs which the user consented to
+    pub consented_providers: Vec<u16>,
+    /// List of Google Ad Tech Provider IDs which were disclosed to the user
+    ///
+    /// Always empty for version 1
+    pub disclosed_providers: Vec<u16>,
+}
+
+/// Value of a [`UsPrivacy`] flag
+///
+/// [`UsPrivacy`]: struct.UsPrivacy.html
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Clone, Copy, PartialOrd, Hash, Debug, Default)]
+pub enum UsPrivacyFlag {
+    /// `Y`
+    Yes,
+    /// `N`
+    No,
+    /// `-`
+    #[default]
+    NotApplicable,
+}
+
+/// `UsPrivacy` contains the four character IAB [`US Privacy String`] (`us_privacy`), e.g. `1YNN`
+///
+/// ```rust,edition2021
+/// use std::convert::TryFrom;
+/// // will return a Result which contains either the UsPrivacy or an Error
+/// // if the string could not be parsed or includes an unsupported version
+/// let us_privacy = lib_tcstring::UsPrivacy::try_from("1YNN");
+/// ```
+///
+/// [`US Privacy String`]: https://github.com/InteractiveAdvertisingBureau/USPrivacy/blob/master/CCPA/US%20Privacy%20String.md
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Clone, PartialOrd, Hash, Debug, Default)]
+pub struct UsPrivacy {
+    /// Version of the US Privacy string
+    pub version: u8,
+    /// Whether explicit notice and the opportunity to opt out was provided
+    pub explicit_notice: UsPrivacyFlag,
+    /// Whether the user has opted out of the sale of personal data
+    pub opt_out_sale: UsPrivacyFlag,
+    /// Whether the publisher is a signatory to the IAB Limited Service Provider Agreement (LSPA)
+    pub lspa_covered: UsPrivacyFlag,
+}
+
+/// Validated [`ISO 639-1`] language code, e.g. `EN` or the extended `SR-LATN`
+///
+/// Only the two letter language is encoded into a TCString, the optional script subtag is used by
+/// TCF v2.2 to distinguish translations of the same language
+///
+/// ```rust,edition2021
+/// use std::convert::TryFrom;
+/// let language_code = lib_tcstring::LanguageCode::try_from("sr-Latn").unwrap();
+///
+/// assert_eq!(language_code.language(), "SR");
+/// assert_eq!(language_code.script(), Some("LATN"));
+/// ```
+///
+/// [`ISO 639-1`]: https://en.wikipedia.org/wiki/ISO_639-1
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "String", into = "String")
+)]
+#[derive(PartialEq, Eq, Clone, PartialOrd, Ord, Hash, Debug)]
+pub struct LanguageCode(pub(crate) String);
+
+/// Validated [`ISO 3166-1 alpha-2`] country code, e.g. `DE`
+///
+/// `AA` is accepted as well, the TCF uses it when the country of the publisher is unknown
+///
+/// ```rust,edition2021
+/// use std::convert::TryFrom;
+/// let country_code = lib_tcstring::CountryCode::try_from("de").unwrap();
+///
+/// assert_eq!(country_code.as_str(), "DE");
+/// ```
+///
+/// [`ISO 3166-1 alpha-2`]: https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "String", into = "String")
+)]
+#[derive(PartialEq, Eq, Clone, PartialOrd, Ord, Hash, Debug)]
+pub struct CountryCode(pub(crate) String);
+
+/// `GppString` contains the header and sections of an IAB [`Global Privacy Platform`] string
+///
+/// The decoded sections are in the same order as the section IDs of the header,
+/// sections which couldn't be decoded are reported in `section_errors` instead
+///
+/// ```rust,edition2021
+/// use std::convert::TryFrom;
+/// // will return a Result which contains either the GppString or an Error
+/// // if the GPP header could not be parsed
+/// let gpp_string = lib_tcstring::GppString::try_from("DBABM~CPXxRfAPXxRfAAfKABENB-CgAAAAAAAAAAYgAAAAAAAA");
+/// ```
+///
+/// [`Global Privacy Platform`]: https://github.com/InteractiveAdvertisingBureau/Global-Privacy-Platform/blob/main/Core/Consent%20String%20Specification.md
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Clone, Debug, Default)]
+pub struct GppString {
+    /// Version of the GPP header
+    pub version: u8,
+    /// List of section IDs contained in the GPP string
+    pub section_ids: Vec<u16>,
+    /// List of sections, see [`GppSection`] for more details
+    ///
+    /// [`GppSection`]: enum.GppSection.html
+    pub sections: Vec<GppSection>,
+    /// List of sections which couldn't be decoded, see [`GppSectionError`] for more details
+    ///
+    /// [`GppSectionError`]: struct.GppSectionError.html
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub section_errors: Vec<GppSectionError>,
+}
+
+/// Section of a [`GppString`] which couldn't be decoded
+///
+/// [`GppString`]: struct.GppString.html
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct GppSectionError {
+    /// Section ID
+    pub id: u16,
+    /// Error which occurred while decoding the section
+    pub error: TcsError,
+}
+
+/// Section of a [`GppString`]
+///
+/// New variants are added for additionally supported sections, so matching requires a wildcard arm
+///
+/// [`GppString`]: struct.GppString.html
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Clone, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum GppSection {
+    /// EU TCF v2 section (section ID `2`)
+    TcfEuV2(Box<TcModelV2>),
+    /// Section which isn't decoded by this library
+    Unknown {
+        /// Section ID
+        id: u16,
+        /// Raw bytes of the base64 decoded section, one entry per `.` separated segment
+        segments: Vec<Vec<u8>>,
+    },
 }
 
 #[cfg_attr(test, derive(Debug))]
 pub(crate) enum RangeSectionType {
-    Vendor(Vec<u16>),
-    VendorLegitimateInterest(Vec<u16>),
-    PublisherRestriction(Vec<PublisherRestriction>),
+    Vendor(VendorSet),
+    VendorLegitimateInterest(VendorSet),
+    PublisherRestriction(PublisherRestrictions),
 }
 
 #[cfg_attr(test, derive(Debug))]
 pub(crate) struct TcSegment {
-    pub disclosed_vendors: Option<Vec<u16>>,
-    pub allowed_vendors: Option<Vec<u16>>,
+    pub disclosed_vendors: Option<VendorSet>,
+    pub allowed_vendors: Option<VendorSet>,
     pub publisher_tc: Option<PublisherTc>,
 }
 
@@ -185,17 +699,40 @@ pub(crate) struct RangeSection {
     pub value: RangeSectionType,
 }
 
-#[cfg_attr(test, derive(Debug))]
-#[derive(Default)]
-pub(crate) struct PublisherTc {
+/// Fields of a publisher TC segment, see [`Segment::PublisherTc`]
+///
+/// [`Segment::PublisherTc`]: enum.Segment.html#variant.PublisherTc
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Clone, PartialOrd, Hash, Debug, Default)]
+pub struct PublisherTc {
+    /// List of purposes which the user consented to for the publisher
     pub publisher_purposes_consent: Vec<u8>,
+    /// List of purposes for which the publisher established "Legitimate Interest"
     pub publisher_purposes_li_transparency: Vec<u8>,
+    /// List of custom purposes which the user consented to
     pub custom_purposes_consent: Vec<u8>,
+    /// List of custom purposes for which the publisher established "Legitimate Interest"
     pub custom_purposes_li_transparency: Vec<u8>,
 }
 
-impl Default for PublisherRestrictionType {
-    fn default() -> Self {
-        Self::Undefined
-    }
+pub(crate) const SEGMENT_BUFFER_LEN: usize = 256;
+
+// the inline variant is meant to live on the stack to avoid allocating for typical core segments
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug)]
+pub(crate) enum SegmentBuffer {
+    Inline {
+        bytes: [u8; SEGMENT_BUFFER_LEN],
+        len: usize,
+    },
+    Heap(Vec<u8>),
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct VendorSectionView {
+    pub max_vendor_id: u16,
+    pub is_range_encoding: bool,
+    pub num_entries: u16,
+    pub bit_start: usize,
+    pub last_bit: usize,
 }
@@ -0,0 +1,190 @@
+use std::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+    ops::RangeInclusive,
+};
+
+use crate::decode::model::{TcModelV2, TcfPolicyVersion};
+
+/// Purposes for which TCF v2.2 doesn't allow "Legitimate Interest" as legal basis
+const NO_LEGITIMATE_INTEREST_PURPOSES: RangeInclusive<u8> = 3..=6;
+
+impl TcfPolicyVersion {
+    /// Returns `true` if the policy allows "Legitimate Interest" as legal basis for the given purpose
+    ///
+    /// Starting with policy version 4 (TCF v2.2) purposes 3 to 6 require consent
+    ///
+    /// ```rust,edition2021
+    /// use lib_tcstring::TcfPolicyVersion;
+    ///
+    /// assert!(TcfPolicyVersion::V2.allows_legitimate_interest(4));
+    /// assert!(!TcfPolicyVersion::V4.allows_legitimate_interest(4));
+    /// assert!(TcfPolicyVersion::V4.allows_legitimate_interest(7));
+    /// ```
+    pub fn allows_legitimate_interest(self, purpose_id: u8) -> bool {
+        u16::from(self) < 4 || !NO_LEGITIMATE_INTEREST_PURPOSES.contains(&purpose_id)
+    }
+}
+
+impl From<u16> for TcfPolicyVersion {
+    fn from(val: u16) -> Self {
+        match val {
+            2 => Self::V2,
+            3 => Self::V3,
+            4 => Self::V4,
+            5 => Self::V5,
+            _ => Self::Unknown(val),
+        }
+    }
+}
+
+impl From<TcfPolicyVersion> for u16 {
+    fn from(val: TcfPolicyVersion) -> Self {
+        match val {
+            TcfPolicyVersion::V2 => 2,
+            TcfPolicyVersion::V3 => 3,
+            TcfPolicyVersion::V4 => 4,
+            TcfPolicyVersion::V5 => 5,
+            TcfPolicyVersion::Unknown(val) => val,
+        }
+    }
+}
+
+impl PartialEq for TcfPolicyVersion {
+    fn eq(&self, other: &Self) -> bool {
+        u16::from(*self) == u16::from(*other)
+    }
+}
+
+impl Eq for TcfPolicyVersion {}
+
+impl PartialOrd for TcfPolicyVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TcfPolicyVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        u16::from(*self).cmp(&u16::from(*other))
+    }
+}
+
+impl Hash for TcfPolicyVersion {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        u16::from(*self).hash(state);
+    }
+}
+
+impl TcModelV2 {
+    /// Returns the [`tcf_policy_version`] as [`TcfPolicyVersion`]
+    ///
+    /// [`tcf_policy_version`]: struct.TcModelV2.html#structfield.tcf_policy_version
+    /// [`TcfPolicyVersion`]: enum.TcfPolicyVersion.html
+    pub fn policy_version(&self) -> TcfPolicyVersion {
+        TcfPolicyVersion::from(self.tcf_policy_version)
+    }
+
+    /// Returns the [`purposes_li_transparency`] which are allowed by the policy version
+    ///
+    /// "Legitimate Interest" signals for purposes 3 to 6 are ignored for TCF v2.2 strings
+    ///
+    /// [`purposes_li_transparency`]: struct.TcModelV2.html#structfield.purposes_li_transparency
+    pub fn allowed_purposes_li_transparency(&self) -> Vec<u8> {
+        self.filter_allowed_li_purposes(&self.purposes_li_transparency)
+    }
+
+    /// Returns the [`publisher_purposes_li_transparency`] which are allowed by the policy version
+    ///
+    /// "Legitimate Interest" signals for purposes 3 to 6 are ignored for TCF v2.2 strings
+    ///
+    /// [`publisher_purposes_li_transparency`]: struct.TcModelV2.html#structfield.publisher_purposes_li_transparency
+    pub fn allowed_publisher_purposes_li_transparency(&self) -> Vec<u8> {
+        self.filter_allowed_li_purposes(&self.publisher_purposes_li_transparency)
+    }
+
+    /// Returns `true` if the TCString contains "Legitimate Interest" signals which the policy version doesn't allow
+    pub fn has_disallowed_li_purposes(&self) -> bool {
+        let policy_version = self.policy_version();
+
+        self.purposes_li_transparency
+            .iter()
+            .chain(self.publisher_purposes_li_transparency.iter())
+            .any(|purpose_id| !policy_version.allows_legitimate_interest(*purpose_id))
+    }
+
+    fn filter_allowed_li_purposes(&self, purpose_ids: &[u8]) -> Vec<u8> {
+        let policy_version = self.policy_version();
+
+        purpose_ids
+            .iter()
+            .copied()
+            .filter(|purpose_id| policy_version.allows_legitimate_interest(*purpose_id))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tcf_policy_version_from_u16() {
+        assert_eq!(TcfPolicyVersion::from(2), TcfPolicyVersion::V2);
+        assert_eq!(TcfPolicyVersion::from(5), TcfPolicyVersion::V5);
+        assert_eq!(TcfPolicyVersion::from(9), TcfPolicyVersion::Unknown(9));
+        assert_eq!(u16::from(TcfPolicyVersion::V4), 4);
+        assert_eq!(u16::from(TcfPolicyVersion::Unknown(9)), 9);
+        assert!(!TcfPolicyVersion::Unknown(9).allows_legitimate_interest(3));
+        assert!(TcfPolicyVersion::Unknown(1).allows_legitimate_interest(3));
+    }
+
+    #[test]
+    fn tcf_policy_version_ordering() {
+        assert!(TcfPolicyVersion::V2 < TcfPolicyVersion::V5);
+        assert!(TcfPolicyVersion::Unknown(1) < TcfPolicyVersion::V2);
+        assert!(TcfPolicyVersion::Unknown(6) > TcfPolicyVersion::V5);
+        assert_eq!(TcfPolicyVersion::Unknown(4), TcfPolicyVersion::V4);
+        assert_eq!(
+            [
+                TcfPolicyVersion::Unknown(9),
+                TcfPolicyVersion::V4,
+                TcfPolicyVersion::Unknown(0),
+            ]
+            .iter()
+            .max(),
+            Some(&TcfPolicyVersion::Unknown(9))
+        );
+    }
+
+    #[test]
+    fn tc_model_allowed_li_purposes() {
+        let mut tc_model = TcModelV2 {
+            tcf_policy_version: 2,
+            purposes_li_transparency: vec![2, 3, 6, 7],
+            publisher_purposes_li_transparency: vec![4, 9],
+            ..TcModelV2::default()
+        };
+
+        assert_eq!(tc_model.policy_version(), TcfPolicyVersion::V2);
+        assert_eq!(
+            tc_model.allowed_purposes_li_transparency(),
+            vec![2, 3, 6, 7]
+        );
+        assert_eq!(
+            tc_model.allowed_publisher_purposes_li_transparency(),
+            vec![4, 9]
+        );
+        assert!(!tc_model.has_disallowed_li_purposes());
+
+        tc_model.tcf_policy_version = 4;
+
+        assert_eq!(tc_model.policy_version(), TcfPolicyVersion::V4);
+        assert_eq!(tc_model.allowed_purposes_li_transparency(), vec![2, 7]);
+        assert_eq!(
+            tc_model.allowed_publisher_purposes_li_transparency(),
+            vec![9]
+        );
+        assert!(tc_model.has_disallowed_li_purposes());
+    }
+}
@@ -0,0 +1,193 @@
+use std::{
+    fmt::{Debug, Formatter},
+    iter::FromIterator,
+};
+
+use crate::decode::model::{PublisherRestriction, PublisherRestrictionType, PublisherRestrictions};
+
+impl PublisherRestrictions {
+    /// Creates empty `PublisherRestrictions`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of publisher restrictions
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if there are no publisher restrictions
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns an iterator over the publisher restrictions in their original order
+    pub fn iter(&self) -> std::slice::Iter<'_, PublisherRestriction> {
+        self.entries.iter()
+    }
+
+    /// Appends a publisher restriction
+    pub fn push(&mut self, publisher_restriction: PublisherRestriction) {
+        self.by_purpose
+            .entry(publisher_restriction.purpose_id)
+            .or_default()
+            .push(self.entries.len());
+        self.entries.push(publisher_restriction);
+    }
+
+    /// Returns the restriction type which applies to the given vendor for the given purpose
+    ///
+    /// [`PublisherRestrictionType::NotAllowed`] takes precedence over any other restriction type,
+    /// [`PublisherRestrictionType::Undefined`] is ignored
+    ///
+    /// [`PublisherRestrictionType::NotAllowed`]: enum.PublisherRestrictionType.html#variant.NotAllowed
+    /// [`PublisherRestrictionType::Undefined`]: enum.PublisherRestrictionType.html#variant.Undefined
+    pub fn restriction_for(
+        &self,
+        purpose_id: u8,
+        vendor_id: u16,
+    ) -> Option<PublisherRestrictionType> {
+        let mut restriction_types = self
+            .for_purpose(purpose_id)
+            .filter(|publisher_restriction| {
+                publisher_restriction.restriction_type != PublisherRestrictionType::Undefined
+                    && publisher_restriction.vendor_list.contains(vendor_id)
+            })
+            .map(|publisher_restriction| &publisher_restriction.restriction_type);
+        let restriction_type = restriction_types.next()?;
+
+        if restriction_types.any(|other| *other == PublisherRestrictionType::NotAllowed) {
+            return Some(PublisherRestrictionType::NotAllowed);
+        }
+
+        Some(restriction_type.clone())
+    }
+
+    /// Returns the publisher restrictions for the given purpose
+    pub fn for_purpose(&self, purpose_id: u8) -> impl Iterator<Item = &PublisherRestriction> + '_ {
+        self.by_purpose
+            .get(&purpose_id)
+            .into_iter()
+            .flatten()
+            .map(move |index| &self.entries[*index])
+    }
+
+    /// Returns the publisher restrictions whose vendor list contains the given vendor, ordered by purpose ID
+    pub fn for_vendor(&self, vendor_id: u16) -> impl Iterator<Item = &PublisherRestriction> + '_ {
+        self.by_purpose
+            .values()
+            .flatten()
+            .map(move |index| &self.entries[*index])
+            .filter(move |publisher_restriction| {
+                publisher_restriction.vendor_list.contains(vendor_id)
+            })
+    }
+
+    /// Returns the restricted purpose IDs in ascending order
+    pub fn purpose_ids(&self) -> impl Iterator<Item = u8> + '_ {
+        self.by_purpose.keys().copied()
+    }
+
+    pub(crate) fn iter_mut(&mut self) -> std::slice::IterMut<'_, PublisherRestriction> {
+        self.entries.iter_mut()
+    }
+}
+
+impl<'a> IntoIterator for &'a PublisherRestrictions {
+    type Item = &'a PublisherRestriction;
+    type IntoIter = std::slice::Iter<'a, PublisherRestriction>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl FromIterator<PublisherRestriction> for PublisherRestrictions {
+    fn from_iter<T: IntoIterator<Item = PublisherRestriction>>(iter: T) -> Self {
+        let mut publisher_restrictions = Self::new();
+
+        for publisher_restriction in iter {
+            publisher_restrictions.push(publisher_restriction);
+        }
+
+        publisher_restrictions
+    }
+}
+
+impl From<Vec<PublisherRestriction>> for PublisherRestrictions {
+    fn from(val: Vec<PublisherRestriction>) -> Self {
+        val.into_iter().collect()
+    }
+}
+
+impl From<PublisherRestrictions> for Vec<PublisherRestriction> {
+    fn from(val: PublisherRestrictions) -> Self {
+        val.entries
+    }
+}
+
+impl Debug for PublisherRestrictions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn publisher_restriction(
+        purpose_id: u8,
+        restriction_type: PublisherRestrictionType,
+        vendor_list: Vec<u16>,
+    ) -> PublisherRestriction {
+        PublisherRestriction {
+            purpose_id,
+            restriction_type,
+            vendor_list: vendor_list.into(),
+        }
+    }
+
+    #[test]
+    fn publisher_restrictions_lookup() {
+        let publisher_restrictions: PublisherRestrictions = vec![
+            publisher_restriction(7, PublisherRestrictionType::RequireConsent, vec![1, 2]),
+            publisher_restriction(2, PublisherRestrictionType::RequireConsent, vec![2, 3]),
+            publisher_restriction(2, PublisherRestrictionType::NotAllowed, vec![3]),
+            publisher_restriction(2, PublisherRestrictionType::Undefined, vec![4]),
+        ]
+        .into();
+
+        assert_eq!(publisher_restrictions.len(), 4);
+        assert_eq!(
+            publisher_restrictions.restriction_for(2, 2),
+            Some(PublisherRestrictionType::RequireConsent)
+        );
+        assert_eq!(
+            publisher_restrictions.restriction_for(2, 3),
+            Some(PublisherRestrictionType::NotAllowed)
+        );
+        assert_eq!(publisher_restrictions.restriction_for(2, 4), None);
+        assert_eq!(publisher_restrictions.restriction_for(7, 3), None);
+        assert_eq!(publisher_restrictions.for_purpose(2).count(), 3);
+        assert_eq!(publisher_restrictions.for_purpose(1).count(), 0);
+        assert_eq!(
+            publisher_restrictions
+                .for_vendor(2)
+                .map(|publisher_restriction| publisher_restriction.purpose_id)
+                .collect::<Vec<_>>(),
+            vec![2, 7]
+        );
+        assert_eq!(
+            publisher_restrictions.purpose_ids().collect::<Vec<_>>(),
+            vec![2, 7]
+        );
+        assert_eq!(
+            publisher_restrictions
+                .iter()
+                .map(|publisher_restriction| publisher_restriction.purpose_id)
+                .collect::<Vec<_>>(),
+            vec![7, 2, 2, 2]
+        );
+    }
+}
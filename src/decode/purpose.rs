@@ -0,0 +1,259 @@
+use std::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+
+use crate::decode::model::{PublisherRestriction, Purpose, SpecialFeature, TcModelV2};
+
+macro_rules! impl_id_ordering {
+    ($id_type: ty) => {
+        impl PartialEq for $id_type {
+            fn eq(&self, other: &Self) -> bool {
+                u8::from(*self) == u8::from(*other)
+            }
+        }
+
+        impl Eq for $id_type {}
+
+        impl PartialOrd for $id_type {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $id_type {
+            fn cmp(&self, other: &Self) -> Ordering {
+                u8::from(*self).cmp(&u8::from(*other))
+            }
+        }
+
+        impl Hash for $id_type {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                u8::from(*self).hash(state);
+            }
+        }
+    };
+}
+
+impl_id_ordering!(Purpose);
+impl_id_ordering!(SpecialFeature);
+
+impl From<u8> for Purpose {
+    fn from(val: u8) -> Self {
+        match val {
+            1 => Self::StoreAccessInformation,
+            2 => Self::SelectBasicAds,
+            3 => Self::CreatePersonalisedAdsProfile,
+            4 => Self::SelectPersonalisedAds,
+            5 => Self::CreatePersonalisedContentProfile,
+            6 => Self::SelectPersonalisedContent,
+            7 => Self::MeasureAdPerformance,
+            8 => Self::MeasureContentPerformance,
+            9 => Self::MarketResearch,
+            10 => Self::DevelopImproveProducts,
+            11 => Self::SelectBasicContent,
+            _ => Self::Unknown(val),
+        }
+    }
+}
+
+impl From<Purpose> for u8 {
+    fn from(val: Purpose) -> Self {
+        match val {
+            Purpose::StoreAccessInformation => 1,
+            Purpose::SelectBasicAds => 2,
+            Purpose::CreatePersonalisedAdsProfile => 3,
+            Purpose::SelectPersonalisedAds => 4,
+            Purpose::CreatePersonalisedContentProfile => 5,
+            Purpose::SelectPersonalisedContent => 6,
+            Purpose::MeasureAdPerformance => 7,
+            Purpose::MeasureContentPerformance => 8,
+            Purpose::MarketResearch => 9,
+            Purpose::DevelopImproveProducts => 10,
+            Purpose::SelectBasicContent => 11,
+            Purpose::Unknown(val) => val,
+        }
+    }
+}
+
+impl From<u8> for SpecialFeature {
+    fn from(val: u8) -> Self {
+        match val {
+            1 => Self::UsePreciseGeolocation,
+            2 => Self::ActivelyScanDeviceCharacteristics,
+            _ => Self::Unknown(val),
+        }
+    }
+}
+
+impl From<SpecialFeature> for u8 {
+    fn from(val: SpecialFeature) -> Self {
+        match val {
+            SpecialFeature::UsePreciseGeolocation => 1,
+            SpecialFeature::ActivelyScanDeviceCharacteristics => 2,
+            SpecialFeature::Unknown(val) => val,
+        }
+    }
+}
+
+impl PublisherRestriction {
+    /// Returns the [`purpose_id`] as [`Purpose`]
+    ///
+    /// [`purpose_id`]: struct.PublisherRestriction.html#structfield.purpose_id
+    /// [`Purpose`]: enum.Purpose.html
+    pub fn purpose(&self) -> Purpose {
+        Purpose::from(self.purpose_id)
+    }
+}
+
+impl TcModelV2 {
+    /// Returns `true` if the user consented to the given purpose
+    ///
+    /// ```rust,edition2021
+    /// use std::convert::TryFrom;
+    /// use lib_tcstring::{Purpose, TcModelV2};
+    /// let tc_model = TcModelV2::try_from("COvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAAAEEUACCKAAA").unwrap();
+    ///
+    /// assert!(tc_model.has_purpose_consent(Purpose::StoreAccessInformation));
+    /// ```
+    pub fn has_purpose_consent(&self, purpose: Purpose) -> bool {
+        self.purposes_consent.contains(&u8::from(purpose))
+    }
+
+    /// Returns `true` if "Legitimate Interest" was established for the given purpose
+    pub fn has_purpose_legitimate_interest(&self, purpose: Purpose) -> bool {
+        self.purposes_li_transparency.contains(&u8::from(purpose))
+    }
+
+    /// Returns `true` if the user opted in to the given special feature
+    pub fn has_special_feature_opt_in(&self, special_feature: SpecialFeature) -> bool {
+        self.special_feature_opt_ins
+            .contains(&u8::from(special_feature))
+    }
+
+    /// Returns `true` if the user consented to the given purpose for the publisher
+    pub fn has_publisher_purpose_consent(&self, purpose: Purpose) -> bool {
+        self.publisher_purposes_consent.contains(&u8::from(purpose))
+    }
+
+    /// Returns `true` if "Legitimate Interest" was established for the given purpose for the publisher
+    pub fn has_publisher_purpose_legitimate_interest(&self, purpose: Purpose) -> bool {
+        self.publisher_purposes_li_transparency
+            .contains(&u8::from(purpose))
+    }
+
+    /// Returns the purposes the user consented to
+    pub fn consented_purposes(&self) -> impl Iterator<Item = Purpose> + '_ {
+        self.purposes_consent.iter().copied().map(Purpose::from)
+    }
+
+    /// Returns the special features the user opted in to
+    pub fn opted_in_special_features(&self) -> impl Iterator<Item = SpecialFeature> + '_ {
+        self.special_feature_opt_ins
+            .iter()
+            .copied()
+            .map(SpecialFeature::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeSet, HashSet};
+
+    use super::*;
+
+    #[test]
+    fn purpose_round_trip() {
+        for purpose_id in 0..=u8::MAX {
+            assert_eq!(u8::from(Purpose::from(purpose_id)), purpose_id);
+            assert_eq!(u8::from(SpecialFeature::from(purpose_id)), purpose_id);
+        }
+
+        assert_eq!(Purpose::from(11), Purpose::SelectBasicContent);
+        assert_eq!(Purpose::from(12), Purpose::Unknown(12));
+        assert_eq!(
+            SpecialFeature::from(2),
+            SpecialFeature::ActivelyScanDeviceCharacteristics
+        );
+        assert_eq!(SpecialFeature::from(3), SpecialFeature::Unknown(3));
+    }
+
+    #[test]
+    fn purpose_ordering() {
+        assert_eq!(Purpose::Unknown(7), Purpose::MeasureAdPerformance);
+        assert_eq!(
+            SpecialFeature::Unknown(1),
+            SpecialFeature::UsePreciseGeolocation
+        );
+        assert!(Purpose::Unknown(1) < Purpose::SelectBasicAds);
+        assert!(Purpose::SelectBasicContent < Purpose::Unknown(12));
+        assert_eq!(
+            [
+                Purpose::Unknown(1),
+                Purpose::StoreAccessInformation,
+                Purpose::SelectBasicContent,
+            ]
+            .iter()
+            .collect::<BTreeSet<_>>()
+            .len(),
+            2
+        );
+        assert_eq!(
+            [
+                SpecialFeature::Unknown(2),
+                SpecialFeature::ActivelyScanDeviceCharacteristics
+            ]
+            .iter()
+            .collect::<HashSet<_>>()
+            .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn tc_model_purposes() {
+        let tc_model = TcModelV2 {
+            special_feature_opt_ins: vec![1],
+            purposes_consent: vec![1, 7],
+            purposes_li_transparency: vec![2],
+            publisher_purposes_consent: vec![9],
+            publisher_purposes_li_transparency: vec![10],
+            publisher_restrictions: vec![PublisherRestriction {
+                purpose_id: 4,
+                ..PublisherRestriction::default()
+            }]
+            .into(),
+            ..TcModelV2::default()
+        };
+
+        assert!(tc_model.has_purpose_consent(Purpose::MeasureAdPerformance));
+        assert!(!tc_model.has_purpose_consent(Purpose::SelectBasicAds));
+        assert!(tc_model.has_purpose_legitimate_interest(Purpose::SelectBasicAds));
+        assert!(tc_model.has_special_feature_opt_in(SpecialFeature::UsePreciseGeolocation));
+        assert!(
+            !tc_model.has_special_feature_opt_in(SpecialFeature::ActivelyScanDeviceCharacteristics)
+        );
+        assert!(tc_model.has_publisher_purpose_consent(Purpose::MarketResearch));
+        assert!(tc_model.has_publisher_purpose_legitimate_interest(Purpose::DevelopImproveProducts));
+        assert_eq!(
+            tc_model.consented_purposes().collect::<Vec<_>>(),
+            vec![
+                Purpose::StoreAccessInformation,
+                Purpose::MeasureAdPerformance
+            ]
+        );
+        assert_eq!(
+            tc_model.opted_in_special_features().collect::<Vec<_>>(),
+            vec![SpecialFeature::UsePreciseGeolocation]
+        );
+        assert_eq!(
+            tc_model
+                .publisher_restrictions
+                .iter()
+                .next()
+                .unwrap()
+                .purpose(),
+            Purpose::SelectPersonalisedAds
+        );
+    }
+}
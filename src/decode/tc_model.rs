@@ -0,0 +1,92 @@
+use std::convert::TryFrom;
+
+use crate::decode::{
+    error::TcsError,
+    model::{DecodeOptions, TcModel, TcModelV1, TcModelV2},
+    util::{decode_segments, parse_version_from_segments},
+};
+
+impl TryFrom<&str> for TcModel {
+    type Error = TcsError;
+
+    fn try_from(val: &str) -> Result<Self, Self::Error> {
+        let tcs_segments = decode_segments(val)?;
+
+        match parse_version_from_segments(&tcs_segments)? {
+            1 => Ok(Self::V1(TcModelV1::try_from_vec(tcs_segments)?)),
+            2 => Ok(Self::V2(Box::new(TcModelV2::try_from_slice(
+                &tcs_segments,
+                &DecodeOptions::default(),
+            )?))),
+            version => Err(TcsError::UnsupportedVersion(version)),
+        }
+    }
+}
+
+impl TcModel {
+    /// Decodes the given TC String into the model matching its version field
+    ///
+    /// Same as `TcModel::try_from`, an unknown version is reported as [`TcsError::UnsupportedVersion`]
+    /// containing the version which was found
+    ///
+    /// ```rust,edition2021
+    /// use lib_tcstring::{TcModel, TcsError};
+    /// let tc_model = TcModel::parse("COvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAAAEEUACCKAAA").unwrap();
+    ///
+    /// assert_eq!(tc_model.version(), 2);
+    /// assert_eq!(
+    ///     TcModel::parse("DOvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAAAEEUACCKAAA"),
+    ///     Err(TcsError::UnsupportedVersion(3))
+    /// );
+    /// ```
+    ///
+    /// [`TcsError::UnsupportedVersion`]: enum.TcsError.html#variant.UnsupportedVersion
+    pub fn parse(val: &str) -> Result<Self, TcsError> {
+        Self::try_from(val)
+    }
+
+    /// Returns the version of the decoded TC String
+    pub fn version(&self) -> u8 {
+        match self {
+            Self::V1(_) => 1,
+            Self::V2(_) => 2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tc_model_version_dispatch() {
+        assert!(matches!(
+            TcModel::try_from("BOEFEAyOEFEAyAHABDENAI4AAAB9vABAASA"),
+            Ok(TcModel::V1(_))
+        ));
+        assert!(matches!(
+            TcModel::try_from("COvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAAAEEUACCKAAA"),
+            Ok(TcModel::V2(_))
+        ));
+        assert_eq!(
+            TcModel::try_from("DOvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAAAEEUACCKAAA"),
+            Err(TcsError::UnsupportedVersion(3))
+        );
+    }
+
+    #[test]
+    fn tc_model_version_mismatch() {
+        assert_eq!(
+            TcModelV1::try_from("COvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAAAEEUACCKAAA"),
+            Err(TcsError::UnsupportedVersion(2))
+        );
+        assert_eq!(
+            TcModelV2::try_from("BOEFEAyOEFEAyAHABDENAI4AAAB9vABAASA"),
+            Err(TcsError::UnsupportedVersion(1))
+        );
+        assert_eq!(
+            TcModel::parse("AOEFEAyOEFEAyAHABDENAI4AAAB9vABAASA"),
+            Err(TcsError::UnsupportedVersion(0))
+        );
+    }
+}
@@ -0,0 +1,181 @@
+use std::convert::TryFrom;
+
+use crate::decode::{
+    error::{ErrorLocation, LocatedError, SegmentType, TcsError},
+    model::{DecodeOptions, RangeSectionType, TcModelV1, VendorSet},
+    util::{
+        decode_segments, parse_from_bytes, parse_string_from_bytes, parse_u8_bitfield_from_bytes,
+        parse_vendor_bitfield_from_bytes, parse_vendor_range_from_bytes,
+        parse_version_from_segments, FieldTable,
+    },
+};
+
+const CORE_FIELDS: FieldTable = FieldTable(&[
+    ("Version", 6),
+    ("Created", 36),
+    ("LastUpdated", 36),
+    ("CmpId", 12),
+    ("CmpVersion", 12),
+    ("ConsentScreen", 6),
+    ("ConsentLanguage", 12),
+    ("VendorListVersion", 12),
+    ("PurposesAllowed", 24),
+]);
+
+fn parse_vendor_section_from_bytes(
+    val: &[u8],
+    bit_start: usize,
+) -> Result<VendorSet, LocatedError> {
+    byte_list_bit_boundary_check!(
+        val,
+        bit_start + 16,
+        ErrorLocation::new("MaxVendorId", bit_start)
+    );
+    byte_list_bit_boundary_check!(
+        val,
+        bit_start + 17,
+        ErrorLocation::new("EncodingType", bit_start + 16)
+    );
+
+    let max_vendor_id = parse_from_bytes(val, bit_start, 16) as u16;
+
+    if parse_from_bytes(val, bit_start + 16, 1) == 0 {
+        return parse_vendor_bitfield_from_bytes(val, bit_start + 17, max_vendor_id as usize);
+    }
+
+    byte_list_bit_boundary_check!(
+        val,
+        bit_start + 18,
+        ErrorLocation::new("DefaultConsent", bit_start + 17)
+    );
+
+    let default_consent = parse_from_bytes(val, bit_start + 17, 1) == 1;
+    let vendor_list = if let RangeSectionType::Vendor(vendor_list) = parse_vendor_range_from_bytes(
+        val,
+        bit_start + 18,
+        &RangeSectionType::Vendor,
+        &DecodeOptions::default(),
+    )?
+    .value
+    {
+        vendor_list
+    } else {
+        return Err(
+            TcsError::UnexpectedRangeSection.at(ErrorLocation::new("EncodingType", bit_start + 16))
+        );
+    };
+
+    if !default_consent {
+        return Ok(vendor_list);
+    }
+
+    let mut vendors_consent = VendorSet::new();
+
+    vendors_consent.insert_range(1..=max_vendor_id);
+
+    for vendor_id in &vendor_list {
+        vendors_consent.remove(vendor_id);
+    }
+
+    Ok(vendors_consent)
+}
+
+impl TryFrom<&str> for TcModelV1 {
+    type Error = TcsError;
+
+    fn try_from(val: &str) -> Result<Self, Self::Error> {
+        Ok(Self::decode_with_location(val)?)
+    }
+}
+
+impl TcModelV1 {
+    /// Decodes the given TCString, errors contain the location at which decoding failed
+    ///
+    /// See [`TcModelV2::decode_with_location`] for more details
+    ///
+    /// [`TcModelV2::decode_with_location`]: struct.TcModelV2.html#method.decode_with_location
+    pub fn decode_with_location(val: &str) -> Result<Self, LocatedError> {
+        let tcs_segments = decode_segments(val)?;
+
+        match parse_version_from_segments(&tcs_segments)? {
+            1 => Self::try_from_vec(tcs_segments),
+            version => Err(TcsError::UnsupportedVersion(version).into()),
+        }
+    }
+
+    pub(crate) fn try_from_vec(val: Vec<Vec<u8>>) -> Result<Self, LocatedError> {
+        if val.len() != 1 {
+            return Err(TcsError::InvalidSegmentDefinition.into());
+        }
+
+        Self::parse_core_segment(val[0].as_slice()).map_err(|err| err.in_segment(SegmentType::Core))
+    }
+
+    fn parse_core_segment(core_segment: &[u8]) -> Result<Self, LocatedError> {
+        CORE_FIELDS.check_length(core_segment)?;
+
+        Ok(Self {
+            created_at: parse_from_bytes(core_segment, 6, 36) * 100,
+            updated_at: parse_from_bytes(core_segment, 42, 36) * 100,
+            cmp_id: parse_from_bytes(core_segment, 78, 12) as u16,
+            cmp_version: parse_from_bytes(core_segment, 90, 12) as u16,
+            consent_screen: parse_from_bytes(core_segment, 102, 6) as u8,
+            consent_language: parse_string_from_bytes(core_segment, 108, 6, 2, "ConsentLanguage")?,
+            vendor_list_version: parse_from_bytes(core_segment, 120, 12) as u16,
+            purposes_consent: parse_u8_bitfield_from_bytes(
+                core_segment,
+                132,
+                24,
+                "PurposesAllowed",
+            )?,
+            vendors_consent: parse_vendor_section_from_bytes(core_segment, CORE_FIELDS.bit_end())?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iab_tcf_v1_vendor_range_default_consent() {
+        assert_eq!(
+            TcModelV1::try_from("BOEFEAyOEFEAyAHABDENAI4AAAB9vABAASA"),
+            Ok(TcModelV1 {
+                created_at: 1510082155400,
+                updated_at: 1510082155400,
+                cmp_id: 7,
+                cmp_version: 1,
+                consent_screen: 3,
+                consent_language: String::from("EN"),
+                vendor_list_version: 8,
+                purposes_consent: vec![1, 2, 3],
+                vendors_consent: (1..=2011).filter(|vendor_id| *vendor_id != 9).collect(),
+            })
+        );
+    }
+
+    #[test]
+    fn iab_tcf_v1_unsupported_version() {
+        assert_eq!(
+            TcModelV1::try_from("COvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAAAEEUACCKAAA"),
+            Err(TcsError::UnsupportedVersion(2))
+        );
+    }
+
+    #[test]
+    fn iab_tcf_v1_insufficient_length() {
+        assert_eq!(
+            TcModelV1::try_from("BOEFEAyOEFEAyAHABDENAI4AAAB9vA"),
+            Err(TcsError::InsufficientLength)
+        );
+        assert_eq!(
+            TcModelV1::decode_with_location("BOEFEAyOEFEAyAHABDENAI4AAAB9vA")
+                .map_err(|err| err.location),
+            Err(Some(ErrorLocation {
+                segment: Some(SegmentType::Core),
+                ..ErrorLocation::new("NumEntries", 174)
+            }))
+        );
+    }
+}
@@ -1,52 +1,82 @@
-use std::convert::TryFrom;
+use std::{convert::TryFrom, ops::RangeInclusive};
 
 use base64::Engine;
 
 use crate::decode::{
-    error::TcsError,
+    error::{ErrorLocation, LocatedError, SegmentType, TcsError},
     model::{
-        PublisherRestriction, PublisherRestrictionType, PublisherTc, RangeSection,
-        RangeSectionType, TcModelV2, TcSegment,
+        DecodeOptions, PublisherRestriction, PublisherRestrictionType, PublisherRestrictions,
+        PublisherTc, RangeSection, RangeSectionType, Segment, TcModelV2, TcSegment, VendorSet,
     },
     util::{
-        parse_from_bytes, parse_string_from_bytes, parse_u16_bitfield_from_bytes,
-        parse_u8_bitfield_from_bytes, parse_vendor_range_from_bytes,
+        check_padding, decode_segments, parse_from_bytes, parse_string_from_bytes,
+        parse_u8_bitfield_from_bytes, parse_vendor_bitfield_from_bytes,
+        parse_vendor_range_from_bytes, parse_version_from_segments, FieldTable, BASE64_ENGINE,
     },
 };
 
-const VENDOR_RANGE_SECTION_TYPES: &[fn(Vec<u16>) -> RangeSectionType; 2] = &[
+const VENDOR_RANGE_SECTION_TYPES: &[fn(VendorSet) -> RangeSectionType; 2] = &[
     RangeSectionType::Vendor,
     RangeSectionType::VendorLegitimateInterest,
 ];
-
-const BASE64_ENGINE: base64::engine::general_purpose::GeneralPurpose =
-    base64::engine::general_purpose::GeneralPurpose::new(
-        &base64::alphabet::URL_SAFE,
-        base64::engine::general_purpose::GeneralPurposeConfig::new()
-            .with_encode_padding(false)
-            .with_decode_padding_mode(base64::engine::DecodePaddingMode::RequireNone),
-    );
+const TCF_POLICY_VERSIONS: RangeInclusive<u16> = 2..=5;
+pub(crate) const CORE_FIELDS: FieldTable = FieldTable(&[
+    ("Version", 6),
+    ("Created", 36),
+    ("LastUpdated", 36),
+    ("CmpId", 12),
+    ("CmpVersion", 12),
+    ("ConsentScreen", 6),
+    ("ConsentLanguage", 12),
+    ("VendorListVersion", 12),
+    ("TcfPolicyVersion", 6),
+    ("IsServiceSpecific", 1),
+    ("UseNonStandardStacks", 1),
+    ("SpecialFeatureOptIns", 12),
+    ("PurposesConsent", 24),
+    ("PurposesLITransparency", 24),
+    ("PurposeOneTreatment", 1),
+    ("PublisherCC", 12),
+]);
 
 fn parse_publisher_restrictions_from_bytes(
     val: &[u8],
     bit_start: usize,
-) -> Result<RangeSection, TcsError> {
-    byte_list_bit_boundary_check!(val, bit_start + 12);
+    options: &DecodeOptions,
+) -> Result<RangeSection, LocatedError> {
+    byte_list_bit_boundary_check!(
+        val,
+        bit_start + 12,
+        ErrorLocation::new("NumPubRestrictions", bit_start)
+    );
 
     let restriction_count = parse_from_bytes(val, bit_start, 12) as usize;
-    let mut publisher_restrictions: Vec<PublisherRestriction> =
-        Vec::with_capacity(restriction_count);
+    let mut publisher_restrictions = PublisherRestrictions::new();
     let mut index: usize = 0;
     let mut bit_index = bit_start + 12;
 
     while index < restriction_count {
-        byte_list_bit_boundary_check!(val, bit_index + 8);
+        byte_list_bit_boundary_check!(
+            val,
+            bit_index + 6,
+            ErrorLocation::new("PurposeId", bit_index)
+        );
+        byte_list_bit_boundary_check!(
+            val,
+            bit_index + 8,
+            ErrorLocation::new("RestrictionType", bit_index + 6)
+        );
 
         let purpose_id = parse_from_bytes(val, bit_index, 6) as u8;
         let restriction_type = parse_from_bytes(val, bit_index + 6, 2) as u8;
-        let section = parse_vendor_range_from_bytes(val, bit_index + 8, &RangeSectionType::Vendor)?;
 
-        bit_index = section.last_bit;
+        if options.strict && restriction_type == 3 {
+            return Err(TcsError::InvalidRestrictionType
+                .at(ErrorLocation::new("RestrictionType", bit_index + 6)));
+        }
+
+        let section =
+            parse_vendor_range_from_bytes(val, bit_index + 8, &RangeSectionType::Vendor, options)?;
 
         publisher_restrictions.push(PublisherRestriction {
             purpose_id,
@@ -59,10 +89,12 @@ fn parse_publisher_restrictions_from_bytes(
             vendor_list: if let RangeSectionType::Vendor(vendor_set) = section.value {
                 vendor_set
             } else {
-                return Err(TcsError::InvalidSectionDefinition);
+                return Err(TcsError::InvalidSectionDefinition
+                    .at(ErrorLocation::new("NumEntries", bit_index + 8)));
             },
         });
 
+        bit_index = section.last_bit;
         index += 1;
     }
 
@@ -75,7 +107,8 @@ fn parse_publisher_restrictions_from_bytes(
 fn parse_range_sections_from_bytes(
     val: &[u8],
     bit_start: usize,
-) -> Result<Vec<RangeSection>, TcsError> {
+    options: &DecodeOptions,
+) -> Result<Vec<RangeSection>, LocatedError> {
     let max_bit_length = val.len() * 8;
     let mut sections: Vec<RangeSection> = Vec::with_capacity(3);
     let mut start = bit_start;
@@ -83,9 +116,21 @@ fn parse_range_sections_from_bytes(
 
     while start < max_bit_length && section_index < 3 {
         let section = if section_index < 2 {
+            byte_list_bit_boundary_check!(
+                val,
+                start + 16,
+                ErrorLocation::new("MaxVendorId", start)
+            );
+            byte_list_bit_boundary_check!(
+                val,
+                start + 17,
+                ErrorLocation::new("IsRangeEncoding", start + 16)
+            );
+
             if parse_from_bytes(val, start + 16, 1) == 0 {
                 let max_vendor_id = parse_from_bytes(val, start, 16) as usize;
-                let bitfield_value = parse_u16_bitfield_from_bytes(val, start + 17, max_vendor_id)?;
+                let bitfield_value =
+                    parse_vendor_bitfield_from_bytes(val, start + 17, max_vendor_id)?;
 
                 RangeSection {
                     last_bit: start + 17 + max_vendor_id,
@@ -96,10 +141,11 @@ fn parse_range_sections_from_bytes(
                     val,
                     start + 17,
                     &VENDOR_RANGE_SECTION_TYPES[section_index],
+                    options,
                 )?
             }
         } else {
-            parse_publisher_restrictions_from_bytes(val, start)?
+            parse_publisher_restrictions_from_bytes(val, start, options)?
         };
 
         start = section.last_bit;
@@ -110,28 +156,75 @@ fn parse_range_sections_from_bytes(
     Ok(sections)
 }
 
-fn parse_vendor_segment_from_bytes(val: &[u8], bit_start: usize) -> Result<Vec<u16>, TcsError> {
+fn parse_vendor_segment_from_bytes(
+    val: &[u8],
+    bit_start: usize,
+    options: &DecodeOptions,
+) -> Result<VendorSet, LocatedError> {
+    byte_list_bit_boundary_check!(
+        val,
+        bit_start + 16,
+        ErrorLocation::new("MaxVendorId", bit_start)
+    );
+    byte_list_bit_boundary_check!(
+        val,
+        bit_start + 17,
+        ErrorLocation::new("IsRangeEncoding", bit_start + 16)
+    );
+
     let max_vendor_id = parse_from_bytes(val, bit_start, 16) as usize;
+    let section = if parse_from_bytes(val, bit_start + 16, 1) == 0 {
+        RangeSection {
+            last_bit: bit_start + 17 + max_vendor_id,
+            value: RangeSectionType::Vendor(parse_vendor_bitfield_from_bytes(
+                val,
+                bit_start + 17,
+                max_vendor_id,
+            )?),
+        }
+    } else {
+        parse_vendor_range_from_bytes(val, bit_start + 17, &RangeSectionType::Vendor, options)?
+    };
+
+    if options.strict {
+        check_padding(val, section.last_bit)?;
+    }
 
-    Ok(if parse_from_bytes(val, bit_start + 16, 1) == 0 {
-        parse_u16_bitfield_from_bytes(val, bit_start + 17, max_vendor_id)?
-    } else if let RangeSectionType::Vendor(vendor_set) =
-        parse_vendor_range_from_bytes(val, bit_start + 17, &RangeSectionType::Vendor)?.value
-    {
-        vendor_set
+    if let RangeSectionType::Vendor(vendor_set) = section.value {
+        Ok(vendor_set)
     } else {
-        return Err(TcsError::UnexpectedRangeSection);
-    })
+        Err(TcsError::UnexpectedRangeSection
+            .at(ErrorLocation::new("IsRangeEncoding", bit_start + 16)))
+    }
 }
 
-fn parse_publisher_tc_from_bytes(val: &[u8], bit_start: usize) -> Result<PublisherTc, TcsError> {
-    let custom_purposes_count = parse_from_bytes(val, bit_start + 48, 6) as usize;
+fn parse_publisher_tc_from_bytes(
+    val: &[u8],
+    bit_start: usize,
+    options: &DecodeOptions,
+) -> Result<PublisherTc, LocatedError> {
+    let publisher_purposes_consent =
+        parse_u8_bitfield_from_bytes(val, bit_start, 24, "PubPurposesConsent")?;
+    let publisher_purposes_li_transparency =
+        parse_u8_bitfield_from_bytes(val, bit_start + 24, 24, "PubPurposesLITransparency")?;
+
+    byte_list_bit_boundary_check!(
+        val,
+        bit_start + 54,
+        ErrorLocation::new("NumCustomPurposes", bit_start + 48)
+    );
 
-    Ok(PublisherTc {
-        publisher_purposes_consent: parse_u8_bitfield_from_bytes(val, bit_start, 24)?,
-        publisher_purposes_li_transparency: parse_u8_bitfield_from_bytes(val, bit_start + 24, 24)?,
+    let custom_purposes_count = parse_from_bytes(val, bit_start + 48, 6) as usize;
+    let publisher_tc = PublisherTc {
+        publisher_purposes_consent,
+        publisher_purposes_li_transparency,
         custom_purposes_consent: if custom_purposes_count > 0 {
-            parse_u8_bitfield_from_bytes(val, bit_start + 54, custom_purposes_count)?
+            parse_u8_bitfield_from_bytes(
+                val,
+                bit_start + 54,
+                custom_purposes_count,
+                "CustomPurposesConsent",
+            )?
         } else {
             vec![]
         },
@@ -140,14 +233,58 @@ fn parse_publisher_tc_from_bytes(val: &[u8], bit_start: usize) -> Result<Publish
                 val,
                 bit_start + 54 + custom_purposes_count,
                 custom_purposes_count,
+                "CustomPurposesLITransparency",
             )?
         } else {
             vec![]
         },
-    })
+    };
+
+    if options.strict {
+        check_padding(val, bit_start + 54 + custom_purposes_count * 2)?;
+    }
+
+    Ok(publisher_tc)
+}
+
+fn parse_segment_type_from_bytes(val: &[u8]) -> Result<SegmentType, LocatedError> {
+    byte_list_bit_boundary_check!(val, 3, ErrorLocation::new("SegmentType", 0));
+
+    match parse_from_bytes(val, 0, 3) {
+        1 => Ok(SegmentType::DisclosedVendors),
+        2 => Ok(SegmentType::AllowedVendors),
+        3 => Ok(SegmentType::PublisherTc),
+        _ => Err(TcsError::InvalidSegmentDefinition.at(ErrorLocation::new("SegmentType", 0))),
+    }
+}
+
+fn parse_segment_from_bytes(
+    val: &[u8],
+    segment_type: SegmentType,
+    options: &DecodeOptions,
+) -> Result<Segment, LocatedError> {
+    let segment = match segment_type {
+        SegmentType::DisclosedVendors => {
+            parse_vendor_segment_from_bytes(val, 3, options).map(Segment::DisclosedVendors)
+        }
+        SegmentType::AllowedVendors => {
+            parse_vendor_segment_from_bytes(val, 3, options).map(Segment::AllowedVendors)
+        }
+        SegmentType::PublisherTc => {
+            parse_publisher_tc_from_bytes(val, 3, options).map(Segment::PublisherTc)
+        }
+        SegmentType::Core => {
+            return Err(TcsError::InvalidSegmentDefinition.at(ErrorLocation::new("SegmentType", 0)))
+        }
+    };
+
+    segment.map_err(|err| err.in_segment(segment_type))
 }
 
-fn parse_tc_segments_from_slice(val: &[Vec<u8>]) -> Result<TcSegment, TcsError> {
+fn parse_tc_segments_from_slice<S: AsRef<[u8]>>(
+    val: &[S],
+    options: &DecodeOptions,
+) -> Result<TcSegment, LocatedError> {
     let mut tc_segment = TcSegment {
         disclosed_vendors: None,
         allowed_vendors: None,
@@ -155,85 +292,235 @@ fn parse_tc_segments_from_slice(val: &[Vec<u8>]) -> Result<TcSegment, TcsError>
     };
 
     for segment in val {
-        let segment_bytes = segment.as_slice();
+        let segment_bytes = segment.as_ref();
+        let segment_type = parse_segment_type_from_bytes(segment_bytes)?;
+        let is_duplicate = match segment_type {
+            SegmentType::DisclosedVendors => tc_segment.disclosed_vendors.is_some(),
+            SegmentType::AllowedVendors => tc_segment.allowed_vendors.is_some(),
+            _ => tc_segment.publisher_tc.is_some(),
+        };
 
-        match parse_from_bytes(segment_bytes, 0, 3) {
-            1 => {
-                tc_segment.disclosed_vendors =
-                    Some(parse_vendor_segment_from_bytes(segment_bytes, 3)?)
-            }
-            2 => {
-                tc_segment.allowed_vendors =
-                    Some(parse_vendor_segment_from_bytes(segment_bytes, 3)?)
+        if options.strict && is_duplicate {
+            return Err(TcsError::DuplicateSegment
+                .at(ErrorLocation::new("SegmentType", 0))
+                .in_segment(segment_type));
+        }
+
+        match parse_segment_from_bytes(segment_bytes, segment_type, options)? {
+            Segment::DisclosedVendors(vendor_set) => {
+                tc_segment.disclosed_vendors = Some(vendor_set)
             }
-            3 => tc_segment.publisher_tc = Some(parse_publisher_tc_from_bytes(segment_bytes, 3)?),
-            _ => return Err(TcsError::InvalidSegmentDefinition),
-        };
+            Segment::AllowedVendors(vendor_set) => tc_segment.allowed_vendors = Some(vendor_set),
+            Segment::PublisherTc(publisher_tc) => tc_segment.publisher_tc = Some(publisher_tc),
+        }
     }
 
     Ok(tc_segment)
 }
 
+fn parse_core_segment_from_bytes(
+    val: &[u8],
+    options: &DecodeOptions,
+) -> Result<TcModelV2, LocatedError> {
+    CORE_FIELDS.check_length(val)?;
+
+    let mut core_sections = parse_range_sections_from_bytes(val, CORE_FIELDS.bit_end(), options)?;
+    let core_end = core_sections
+        .last()
+        .map_or(CORE_FIELDS.bit_end(), |section| section.last_bit);
+
+    if options.strict {
+        check_padding(val, core_end)?;
+    }
+
+    Ok(TcModelV2 {
+        created_at: parse_from_bytes(val, 6, 36) * 100,
+        updated_at: parse_from_bytes(val, 42, 36) * 100,
+        cmp_id: parse_from_bytes(val, 78, 12) as u16,
+        cmp_version: parse_from_bytes(val, 90, 12) as u16,
+        consent_screen: parse_from_bytes(val, 102, 6) as u8,
+        consent_language: parse_string_from_bytes(val, 108, 6, 2, "ConsentLanguage")?,
+        vendor_list_version: parse_from_bytes(val, 120, 12) as u16,
+        tcf_policy_version: parse_from_bytes(val, 132, 6) as u16,
+        is_service_specific: parse_from_bytes(val, 138, 1) == 1,
+        use_non_standard_stacks: parse_from_bytes(val, 139, 1) == 1,
+        special_feature_opt_ins: parse_u8_bitfield_from_bytes(
+            val,
+            140,
+            12,
+            "SpecialFeatureOptIns",
+        )?,
+        purposes_consent: parse_u8_bitfield_from_bytes(val, 152, 24, "PurposesConsent")?,
+        purposes_li_transparency: parse_u8_bitfield_from_bytes(
+            val,
+            176,
+            24,
+            "PurposesLITransparency",
+        )?,
+        purpose_one_treatment: parse_from_bytes(val, 200, 1) == 1,
+        publisher_country_code: parse_string_from_bytes(val, 201, 6, 2, "PublisherCC")?,
+        vendors_consent: range_section_value!(
+            core_sections,
+            RangeSectionType::Vendor,
+            ErrorLocation::new("MaxVendorId", core_end)
+        ),
+        vendors_li_consent: range_section_value!(
+            core_sections,
+            RangeSectionType::VendorLegitimateInterest,
+            ErrorLocation::new("MaxVendorId", core_end)
+        ),
+        publisher_restrictions: range_section_value!(
+            core_sections,
+            RangeSectionType::PublisherRestriction,
+            ErrorLocation::new("NumPubRestrictions", core_end)
+        ),
+        ..TcModelV2::default()
+    })
+}
+
 impl TryFrom<&str> for TcModelV2 {
     type Error = TcsError;
 
     fn try_from(val: &str) -> Result<Self, Self::Error> {
-        if !val.starts_with('C') {
-            return Err(TcsError::UnsupportedVersion);
-        }
+        Self::decode_with_options(val, &DecodeOptions::default())
+    }
+}
 
-        let mut tcs_segments: Vec<Vec<u8>> = Vec::with_capacity(4);
+/// Decodes a TCString which only consists of the base64-decoded bytes of the core segment,
+/// use [`TcModelV2::from_segments`] to decode additional segments
+///
+/// [`TcModelV2::from_segments`]: struct.TcModelV2.html#method.from_segments
+impl TryFrom<&[u8]> for TcModelV2 {
+    type Error = TcsError;
 
-        for base64_str in val.split('.') {
-            if base64_str.is_empty() {
-                return Err(TcsError::InsufficientLength);
-            }
+    fn try_from(val: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_segments(&[val])
+    }
+}
 
-            tcs_segments.push(match BASE64_ENGINE.decode(base64_str) {
-                Ok(decoded_bytes) => decoded_bytes,
-                Err(err) => return Err(TcsError::InvalidUrlSafeBase64(err)),
-            });
+impl Segment {
+    /// Decodes the base64-decoded bytes of a single non-core segment using the given [`DecodeOptions`]
+    ///
+    /// [`DecodeOptions`]: struct.DecodeOptions.html
+    pub fn decode_with_options(val: &[u8], options: &DecodeOptions) -> Result<Self, TcsError> {
+        Ok(parse_segment_from_bytes(
+            val,
+            parse_segment_type_from_bytes(val)?,
+            options,
+        )?)
+    }
+
+    /// Returns the type of the segment
+    pub fn segment_type(&self) -> SegmentType {
+        match self {
+            Segment::DisclosedVendors(_) => SegmentType::DisclosedVendors,
+            Segment::AllowedVendors(_) => SegmentType::AllowedVendors,
+            Segment::PublisherTc(_) => SegmentType::PublisherTc,
         }
+    }
+}
+
+impl TryFrom<&[u8]> for Segment {
+    type Error = TcsError;
 
-        Self::try_from_vec(tcs_segments)
+    fn try_from(val: &[u8]) -> Result<Self, Self::Error> {
+        Self::decode_with_options(val, &DecodeOptions::default())
+    }
+}
+
+impl TryFrom<&str> for Segment {
+    type Error = TcsError;
+
+    fn try_from(val: &str) -> Result<Self, Self::Error> {
+        Self::try_from(
+            BASE64_ENGINE
+                .decode(val)
+                .map_err(TcsError::InvalidUrlSafeBase64)?
+                .as_slice(),
+        )
     }
 }
 
 impl TcModelV2 {
-    fn try_from_vec(val: Vec<Vec<u8>>) -> Result<Self, TcsError> {
-        let core_segment = val[0].as_slice();
+    /// Decodes the given TCString using the given [`DecodeOptions`]
+    ///
+    /// ```rust,edition2021
+    /// use lib_tcstring::{DecodeOptions, TcModelV2};
+    /// // rejects TC Strings which violate the specification
+    /// let tc_model = TcModelV2::decode_with_options(
+    ///     "COvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAAAEEUACCKAAA",
+    ///     &DecodeOptions { strict: true },
+    /// );
+    /// ```
+    ///
+    /// [`DecodeOptions`]: struct.DecodeOptions.html
+    pub fn decode_with_options(val: &str, options: &DecodeOptions) -> Result<Self, TcsError> {
+        Ok(Self::decode_with_location(val, options)?)
+    }
 
-        byte_list_bit_boundary_check!(core_segment, 213);
+    /// Decodes the given TCString using the given [`DecodeOptions`], errors contain the location at which decoding failed
+    ///
+    /// ```rust,edition2021
+    /// use lib_tcstring::{DecodeOptions, ErrorLocation, SegmentType, TcModelV2, TcsError};
+    /// let err = TcModelV2::decode_with_location(
+    ///     "COvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAAAEEUACCK",
+    ///     &DecodeOptions::default(),
+    /// )
+    /// .unwrap_err();
+    ///
+    /// assert_eq!(err.error, TcsError::InsufficientLength);
+    /// assert_eq!(err.location.and_then(|location| location.segment), Some(SegmentType::Core));
+    /// ```
+    ///
+    /// [`DecodeOptions`]: struct.DecodeOptions.html
+    pub fn decode_with_location(val: &str, options: &DecodeOptions) -> Result<Self, LocatedError> {
+        let tcs_segments = decode_segments(val)?;
 
-        let mut core_sections = parse_range_sections_from_bytes(core_segment, 213)?;
-        let segments = parse_tc_segments_from_slice(&val[1..])?;
-        let publisher_segment = segments.publisher_tc.unwrap_or_default();
+        match parse_version_from_segments(&tcs_segments)? {
+            2 => Self::try_from_slice(&tcs_segments, options),
+            version => Err(TcsError::UnsupportedVersion(version).into()),
+        }
+    }
 
-        Ok(Self {
-            created_at: parse_from_bytes(core_segment, 6, 36) * 100,
-            updated_at: parse_from_bytes(core_segment, 42, 36) * 100,
-            cmp_id: parse_from_bytes(core_segment, 78, 12) as u16,
-            cmp_version: parse_from_bytes(core_segment, 90, 12) as u16,
-            consent_screen: parse_from_bytes(core_segment, 102, 6) as u8,
-            consent_language: parse_string_from_bytes(core_segment, 108, 6, 2)?,
-            vendor_list_version: parse_from_bytes(core_segment, 120, 12) as u16,
-            tcf_policy_version: parse_from_bytes(core_segment, 132, 6) as u16,
-            is_service_specific: parse_from_bytes(core_segment, 138, 1) == 1,
-            use_non_standard_stacks: parse_from_bytes(core_segment, 139, 1) == 1,
-            special_feature_opt_ins: parse_u8_bitfield_from_bytes(core_segment, 140, 12)?,
-            purposes_consent: parse_u8_bitfield_from_bytes(core_segment, 152, 24)?,
-            purposes_li_transparency: parse_u8_bitfield_from_bytes(core_segment, 176, 24)?,
-            purpose_one_treatment: parse_from_bytes(core_segment, 200, 1) == 1,
-            publisher_country_code: parse_string_from_bytes(core_segment, 201, 6, 2)?,
-            vendors_consent: range_section_value!(core_sections, RangeSectionType::Vendor),
-            vendors_li_consent: range_section_value!(
-                core_sections,
-                RangeSectionType::VendorLegitimateInterest
-            ),
-            publisher_restrictions: range_section_value!(
-                core_sections,
-                RangeSectionType::PublisherRestriction
-            ),
+    /// Decodes a TCString from the base64-decoded bytes of its segments, the core segment comes first
+    ///
+    /// ```rust,edition2021
+    /// use lib_tcstring::TcModelV2;
+    /// let core_segment = [
+    ///     8, 235, 197, 200, 96, 78, 188, 92, 134, 4, 6, 192, 0, 1, 13, 0, 240, 128, 0, 224, 0, 0, 0, 0,
+    ///     0, 0, 0, 0, 65, 20, 0, 32, 138, 0, 0,
+    /// ];
+    /// let tc_model = TcModelV2::from_segments(&[&core_segment]).unwrap();
+    ///
+    /// assert!(tc_model.vendors_consent.contains(8));
+    /// ```
+    pub fn from_segments(val: &[&[u8]]) -> Result<Self, TcsError> {
+        Self::from_segments_with_options(val, &DecodeOptions::default())
+    }
+
+    /// Decodes a TCString from the base64-decoded bytes of its segments using the given [`DecodeOptions`]
+    ///
+    /// [`DecodeOptions`]: struct.DecodeOptions.html
+    pub fn from_segments_with_options(
+        val: &[&[u8]],
+        options: &DecodeOptions,
+    ) -> Result<Self, TcsError> {
+        match parse_version_from_segments(val)? {
+            2 => Ok(Self::try_from_slice(val, options)?),
+            version => Err(TcsError::UnsupportedVersion(version)),
+        }
+    }
+
+    pub(crate) fn try_from_slice<S: AsRef<[u8]>>(
+        val: &[S],
+        options: &DecodeOptions,
+    ) -> Result<Self, LocatedError> {
+        let core_segment = val.first().map_or(&[][..], |segment| segment.as_ref());
+        let tc_model = parse_core_segment_from_bytes(core_segment, options)
+            .map_err(|err| err.in_segment(SegmentType::Core))?;
+        let segments = parse_tc_segments_from_slice(val.get(1..).unwrap_or_default(), options)?;
+        let publisher_segment = segments.publisher_tc.unwrap_or_default();
+        let tc_model = Self {
             disclosed_vendors: segments.disclosed_vendors.unwrap_or_default(),
             allowed_vendors: segments.allowed_vendors.unwrap_or_default(),
             publisher_purposes_consent: publisher_segment.publisher_purposes_consent,
@@ -241,13 +528,27 @@ impl TcModelV2 {
                 .publisher_purposes_li_transparency,
             custom_purposes_consent: publisher_segment.custom_purposes_consent,
             custom_purposes_li_transparency: publisher_segment.custom_purposes_li_transparency,
-        })
+            ..tc_model
+        };
+
+        if options.strict {
+            if tc_model.created_at > tc_model.updated_at {
+                return Err(TcsError::InvalidTimestamps.into());
+            }
+
+            if !TCF_POLICY_VERSIONS.contains(&tc_model.tcf_policy_version) {
+                return Err(TcsError::UnsupportedPolicyVersion(tc_model.tcf_policy_version).into());
+            }
+        }
+
+        Ok(tc_model)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{decode::fixtures::TC_STRINGS, encode::util::BitWriter};
 
     #[test]
     fn iab_tcf_v2_core_vendor_range() {
@@ -269,11 +570,11 @@ mod tests {
                 purposes_li_transparency: vec![],
                 purpose_one_treatment: false,
                 publisher_country_code: String::from("AA"),
-                vendors_consent: vec![2, 6, 8],
-                vendors_li_consent: vec![2, 6, 8],
-                publisher_restrictions: vec![],
-                disclosed_vendors: vec![],
-                allowed_vendors: vec![],
+                vendors_consent: vec![2, 6, 8].into(),
+                vendors_li_consent: vec![2, 6, 8].into(),
+                publisher_restrictions: vec![].into(),
+                disclosed_vendors: vec![].into(),
+                allowed_vendors: vec![].into(),
                 publisher_purposes_consent: vec![],
                 publisher_purposes_li_transparency: vec![],
                 custom_purposes_consent: vec![],
@@ -302,11 +603,11 @@ mod tests {
                 purposes_li_transparency: vec![],
                 purpose_one_treatment: false,
                 publisher_country_code: String::from("AA"),
-                vendors_consent: vec![],
-                vendors_li_consent: vec![],
-                publisher_restrictions: vec![],
-                disclosed_vendors: vec![2, 6, 8, 9, 12, 15, 18, 23, 37, 42, 47, 48, 53, 61, 65, 66, 68, 72, 80, 88, 89, 90, 93, 98, 100, 126, 127, 128, 129, 133, 153, 163, 167, 174, 177, 192, 205, 215, 224, 228, 243, 248, 262, 281, 294, 302, 304, 314, 325, 350, 351, 358, 371, 402, 415, 422, 424, 439, 440, 447, 450, 467, 486, 491, 495, 498, 502, 512, 516, 553, 554, 556, 571, 587, 593, 607, 612, 613, 618, 626, 628, 648, 652, 653, 656, 657, 659, 665, 676, 681, 683, 684, 686, 687, 688, 690, 691, 694, 699, 702, 703, 707, 708, 711, 712, 714, 716, 719, 720, 722, 723, 725, 726, 729, 733],
-                allowed_vendors: vec![2, 6, 8, 9, 12, 15, 18, 23, 37, 42, 47, 48, 53, 61, 65, 66, 68, 72, 80, 88, 89, 90, 93, 98, 100, 126, 127, 128, 129, 133, 153, 163, 167, 174, 177, 192, 205, 215, 224, 228, 243, 248, 262, 281, 294, 302, 304, 314, 325, 350, 351, 358, 371, 402, 415, 422, 424, 439, 440, 447, 450, 467, 486, 491, 495, 498, 502, 512, 516, 553, 554, 556, 571, 587, 593, 607, 612, 613, 618, 626, 628, 648, 652, 653, 656, 657, 659, 665, 676, 681, 683, 684, 686, 687, 688, 690, 691, 694, 699, 702, 703, 707, 708, 711, 712, 714, 716, 719, 720, 722, 723, 725, 726, 729, 733],
+                vendors_consent: vec![].into(),
+                vendors_li_consent: vec![].into(),
+                publisher_restrictions: vec![].into(),
+                disclosed_vendors: vec![2, 6, 8, 9, 12, 15, 18, 23, 37, 42, 47, 48, 53, 61, 65, 66, 68, 72, 80, 88, 89, 90, 93, 98, 100, 126, 127, 128, 129, 133, 153, 163, 167, 174, 177, 192, 205, 215, 224, 228, 243, 248, 262, 281, 294, 302, 304, 314, 325, 350, 351, 358, 371, 402, 415, 422, 424, 439, 440, 447, 450, 467, 486, 491, 495, 498, 502, 512, 516, 553, 554, 556, 571, 587, 593, 607, 612, 613, 618, 626, 628, 648, 652, 653, 656, 657, 659, 665, 676, 681, 683, 684, 686, 687, 688, 690, 691, 694, 699, 702, 703, 707, 708, 711, 712, 714, 716, 719, 720, 722, 723, 725, 726, 729, 733].into(),
+                allowed_vendors: vec![2, 6, 8, 9, 12, 15, 18, 23, 37, 42, 47, 48, 53, 61, 65, 66, 68, 72, 80, 88, 89, 90, 93, 98, 100, 126, 127, 128, 129, 133, 153, 163, 167, 174, 177, 192, 205, 215, 224, 228, 243, 248, 262, 281, 294, 302, 304, 314, 325, 350, 351, 358, 371, 402, 415, 422, 424, 439, 440, 447, 450, 467, 486, 491, 495, 498, 502, 512, 516, 553, 554, 556, 571, 587, 593, 607, 612, 613, 618, 626, 628, 648, 652, 653, 656, 657, 659, 665, 676, 681, 683, 684, 686, 687, 688, 690, 691, 694, 699, 702, 703, 707, 708, 711, 712, 714, 716, 719, 720, 722, 723, 725, 726, 729, 733].into(),
                 publisher_purposes_consent: vec![],
                 publisher_purposes_li_transparency: vec![],
                 custom_purposes_consent: vec![],
@@ -335,11 +636,11 @@ mod tests {
                 purposes_li_transparency: vec![],
                 purpose_one_treatment: false,
                 publisher_country_code: String::from("AA"),
-                vendors_consent: vec![],
-                vendors_li_consent: vec![],
-                publisher_restrictions: vec![],
-                disclosed_vendors: vec![2, 6, 8, 9, 12, 15, 18, 23, 37, 42, 47, 48, 53, 61, 65, 66, 68, 72, 80, 88, 89, 90, 93, 98, 100, 126, 127, 128, 129, 133, 153, 163, 167, 174, 177, 192, 205, 215, 224, 228, 243, 248, 262, 281, 294, 302, 304, 314, 325, 350, 351, 358, 371, 402, 415, 422, 424, 439, 440, 447, 450, 467, 486, 491, 495, 498, 502, 512, 516, 553, 554, 556, 571, 587, 593, 607, 612, 613, 618, 626, 628, 648, 652, 653, 656, 657, 659, 665, 676, 681, 683, 684, 686, 687, 688, 690, 691, 694, 699, 702, 703, 707, 708, 711, 712, 714, 716, 719, 720, 722, 723, 725, 726, 729, 733],
-                allowed_vendors: vec![2, 6, 8, 9, 12, 15, 18, 23, 37, 42, 47, 48, 53, 61, 65, 66, 68, 72, 80, 88, 89, 90, 93, 98, 100, 126, 127, 128, 129, 133, 153, 163, 167, 174, 177, 192, 205, 215, 224, 228, 243, 248, 262, 281, 294, 302, 304, 314, 325, 350, 351, 358, 371, 402, 415, 422, 424, 439, 440, 447, 450, 467, 486, 491, 495, 498, 502, 512, 516, 553, 554, 556, 571, 587, 593, 607, 612, 613, 618, 626, 628, 648, 652, 653, 656, 657, 659, 665, 676, 681, 683, 684, 686, 687, 688, 690, 691, 694, 699, 702, 703, 707, 708, 711, 712, 714, 716, 719, 720, 722, 723, 725, 726, 729, 733],
+                vendors_consent: vec![].into(),
+                vendors_li_consent: vec![].into(),
+                publisher_restrictions: vec![].into(),
+                disclosed_vendors: vec![2, 6, 8, 9, 12, 15, 18, 23, 37, 42, 47, 48, 53, 61, 65, 66, 68, 72, 80, 88, 89, 90, 93, 98, 100, 126, 127, 128, 129, 133, 153, 163, 167, 174, 177, 192, 205, 215, 224, 228, 243, 248, 262, 281, 294, 302, 304, 314, 325, 350, 351, 358, 371, 402, 415, 422, 424, 439, 440, 447, 450, 467, 486, 491, 495, 498, 502, 512, 516, 553, 554, 556, 571, 587, 593, 607, 612, 613, 618, 626, 628, 648, 652, 653, 656, 657, 659, 665, 676, 681, 683, 684, 686, 687, 688, 690, 691, 694, 699, 702, 703, 707, 708, 711, 712, 714, 716, 719, 720, 722, 723, 725, 726, 729, 733].into(),
+                allowed_vendors: vec![2, 6, 8, 9, 12, 15, 18, 23, 37, 42, 47, 48, 53, 61, 65, 66, 68, 72, 80, 88, 89, 90, 93, 98, 100, 126, 127, 128, 129, 133, 153, 163, 167, 174, 177, 192, 205, 215, 224, 228, 243, 248, 262, 281, 294, 302, 304, 314, 325, 350, 351, 358, 371, 402, 415, 422, 424, 439, 440, 447, 450, 467, 486, 491, 495, 498, 502, 512, 516, 553, 554, 556, 571, 587, 593, 607, 612, 613, 618, 626, 628, 648, 652, 653, 656, 657, 659, 665, 676, 681, 683, 684, 686, 687, 688, 690, 691, 694, 699, 702, 703, 707, 708, 711, 712, 714, 716, 719, 720, 722, 723, 725, 726, 729, 733].into(),
                 publisher_purposes_consent: vec![],
                 publisher_purposes_li_transparency: vec![],
                 custom_purposes_consent: vec![],
@@ -368,11 +669,11 @@ mod tests {
                 purposes_li_transparency: vec![2, 3, 5, 7, 9, 10],
                 purpose_one_treatment: false,
                 publisher_country_code: String::from("HL"),
-                vendors_consent: vec![4, 5, 7, 8, 10, 11, 12, 13, 15, 16, 17, 18, 19, 20, 21, 23, 24, 25, 26, 27, 28, 30, 31, 32, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 45, 46, 47, 48, 49, 50, 51, 52, 53, 55, 56, 57, 58, 60, 61, 62, 64, 65, 66, 67, 68, 69, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 83, 86, 87, 88, 89, 91, 93, 94, 96, 97, 98, 99, 100, 101, 102, 103, 107, 108, 109, 110, 111, 112, 113, 114, 115],
-                vendors_li_consent: vec![4, 7, 8, 11, 12, 13, 19, 22, 27, 31, 33, 35, 37, 43, 45, 47, 48, 49, 54, 55, 59, 64, 65, 68, 69, 77, 79, 81, 94, 95, 110, 113],
-                publisher_restrictions: vec![],
-                disclosed_vendors: vec![],
-                allowed_vendors: vec![],
+                vendors_consent: vec![4, 5, 7, 8, 10, 11, 12, 13, 15, 16, 17, 18, 19, 20, 21, 23, 24, 25, 26, 27, 28, 30, 31, 32, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 45, 46, 47, 48, 49, 50, 51, 52, 53, 55, 56, 57, 58, 60, 61, 62, 64, 65, 66, 67, 68, 69, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 83, 86, 87, 88, 89, 91, 93, 94, 96, 97, 98, 99, 100, 101, 102, 103, 107, 108, 109, 110, 111, 112, 113, 114, 115].into(),
+                vendors_li_consent: vec![4, 7, 8, 11, 12, 13, 19, 22, 27, 31, 33, 35, 37, 43, 45, 47, 48, 49, 54, 55, 59, 64, 65, 68, 69, 77, 79, 81, 94, 95, 110, 113].into(),
+                publisher_restrictions: vec![].into(),
+                disclosed_vendors: vec![].into(),
+                allowed_vendors: vec![].into(),
                 publisher_purposes_consent: vec![],
                 publisher_purposes_li_transparency: vec![],
                 custom_purposes_consent: vec![],
@@ -401,11 +702,11 @@ mod tests {
                 purposes_li_transparency: vec![],
                 purpose_one_treatment: false,
                 publisher_country_code: String::from("AA"),
-                vendors_consent: vec![2, 6, 8],
-                vendors_li_consent: vec![2, 6, 8],
-                publisher_restrictions: vec![],
-                disclosed_vendors: vec![2, 6, 8, 12, 18, 23, 37, 42, 47, 48, 53, 61, 65, 66, 72, 88, 98, 127, 128, 129, 133, 153, 163, 192, 205, 215, 224, 243, 248, 281, 294, 304, 350, 351, 358, 371, 422, 424, 440, 447, 467, 486, 498, 502, 512, 516, 553, 556, 571, 587, 612, 613, 618, 626, 648, 653, 656, 657, 665, 676, 681, 683, 684, 686, 687, 688, 690, 691, 694, 702, 703, 707, 708, 711, 712, 714, 716, 719, 720],
-                allowed_vendors: vec![],
+                vendors_consent: vec![2, 6, 8].into(),
+                vendors_li_consent: vec![2, 6, 8].into(),
+                publisher_restrictions: vec![].into(),
+                disclosed_vendors: vec![2, 6, 8, 12, 18, 23, 37, 42, 47, 48, 53, 61, 65, 66, 72, 88, 98, 127, 128, 129, 133, 153, 163, 192, 205, 215, 224, 243, 248, 281, 294, 304, 350, 351, 358, 371, 422, 424, 440, 447, 467, 486, 498, 502, 512, 516, 553, 556, 571, 587, 612, 613, 618, 626, 648, 653, 656, 657, 665, 676, 681, 683, 684, 686, 687, 688, 690, 691, 694, 702, 703, 707, 708, 711, 712, 714, 716, 719, 720].into(),
+                allowed_vendors: vec![].into(),
                 publisher_purposes_consent: vec![],
                 publisher_purposes_li_transparency: vec![],
                 custom_purposes_consent: vec![],
@@ -436,11 +737,11 @@ mod tests {
                 purposes_li_transparency: vec![12, 13, 14, 15, 16, 17, 18],
                 purpose_one_treatment: true,
                 publisher_country_code: String::from("AA"),
-                vendors_consent: vec![2, 3, 4, 5],
-                vendors_li_consent: vec![1, 2, 3, 4],
-                publisher_restrictions: vec![],
-                disclosed_vendors: vec![],
-                allowed_vendors: vec![],
+                vendors_consent: vec![2, 3, 4, 5].into(),
+                vendors_li_consent: vec![1, 2, 3, 4].into(),
+                publisher_restrictions: vec![].into(),
+                disclosed_vendors: vec![].into(),
+                allowed_vendors: vec![].into(),
                 publisher_purposes_consent: vec![1, 13, 24],
                 publisher_purposes_li_transparency: vec![1, 2, 3],
                 custom_purposes_consent: vec![2, 3, 4, 19, 20, 21, 22, 23],
@@ -469,15 +770,15 @@ mod tests {
                 purposes_li_transparency: vec![12, 13, 14, 15, 16, 17, 18],
                 purpose_one_treatment: true,
                 publisher_country_code: String::from("AA"),
-                vendors_consent: vec![2, 3, 4, 5],
-                vendors_li_consent: vec![1, 2, 3, 4],
+                vendors_consent: vec![2, 3, 4, 5].into(),
+                vendors_li_consent: vec![1, 2, 3, 4].into(),
                 publisher_restrictions: vec![PublisherRestriction {
                     purpose_id: 1,
                     restriction_type: PublisherRestrictionType::RequireConsent,
-                    vendor_list: vec![1, 2, 3, 4, 5, 6, 7],
-                }],
-                disclosed_vendors: vec![1, 2, 3, 4, 5, 6, 19, 20, 21, 22, 23, 25, 27, 28, 29, 30],
-                allowed_vendors: vec![1, 2, 3, 4, 5, 6, 19, 20, 21, 22, 23, 25, 27, 28, 29, 30],
+                    vendor_list: vec![1, 2, 3, 4, 5, 6, 7].into(),
+                }].into(),
+                disclosed_vendors: vec![1, 2, 3, 4, 5, 6, 19, 20, 21, 22, 23, 25, 27, 28, 29, 30].into(),
+                allowed_vendors: vec![1, 2, 3, 4, 5, 6, 19, 20, 21, 22, 23, 25, 27, 28, 29, 30].into(),
                 publisher_purposes_consent: vec![1, 13, 24],
                 publisher_purposes_li_transparency: vec![1, 2, 3],
                 custom_purposes_consent: vec![2, 3, 4, 19, 20, 21, 22, 23],
@@ -507,47 +808,47 @@ mod tests {
                     purposes_li_transparency: vec![],
                     purpose_one_treatment: false,
                     publisher_country_code: String::from("DE"),
-                    vendors_consent: vec![1, 2, 4, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 18, 20, 21, 22, 23, 24, 25, 26, 27, 28, 30, 31, 32, 33, 34, 36, 37, 39, 40, 41, 42, 44, 45, 47, 49, 50, 51, 52, 53, 57, 58, 59, 60, 61, 62, 63, 65, 66, 67, 68, 69, 70, 71, 72, 73, 76, 77, 78, 79, 80, 82, 83, 84, 85, 86, 88, 89, 90, 91, 92, 94, 95, 97, 98, 100, 101, 102, 104, 108, 109, 110, 111, 114, 115, 119, 120, 122, 124, 126, 127, 128, 129, 130, 131, 132, 133, 134, 136, 137, 138, 139, 140, 141, 142, 143, 144, 145, 147, 149, 150, 152, 153, 154, 155, 157, 158, 159, 160, 161, 162, 163, 164, 165, 167, 168, 173, 174, 177, 178, 179, 183, 184, 185, 192, 193, 194, 195, 199, 200, 202, 203, 205, 206, 209, 210, 211, 212, 213, 215, 216, 217, 218, 223, 224, 226, 227, 228, 231, 234, 235, 236, 238, 239, 240, 241, 242, 243, 244, 246, 248, 249, 250, 251, 252, 253, 254, 255, 256, 259, 261, 262, 263, 264, 265, 270, 272, 273, 274, 275, 277, 279, 280, 281, 282, 284, 285, 289, 290, 293, 294, 297, 299, 301, 302, 303, 304, 310, 311, 312, 314, 315, 316, 317, 318, 319, 321, 323, 325, 328, 329, 331, 333, 335, 336, 337, 343, 345, 347, 349, 350, 351, 354, 358, 359, 360, 361, 368, 371, 373, 374, 375, 377, 378, 380, 381, 382, 385, 387, 388, 394, 402, 408, 409, 410, 412, 413, 416, 418, 422, 423, 424, 427, 428, 429, 434, 435, 436, 438, 439, 440, 444, 447, 448, 450, 455, 458, 459, 462, 467, 468, 475, 479, 482, 486, 488, 490, 491, 493, 495, 498, 501, 502, 505, 507, 508, 509, 511, 512, 516, 517, 519, 520, 521, 524, 527, 528, 530, 531, 535, 536, 539, 541, 543, 545, 546, 547, 549, 550, 553, 554, 556, 559, 561, 565, 568, 569, 570, 571, 573, 574, 577, 579, 580, 584, 587, 591, 593, 596, 598, 601, 602, 606, 607, 609, 610, 613, 614, 617, 618, 620, 621, 624, 625, 626, 628, 630, 631, 638, 639, 644, 645, 646, 647, 648, 649, 650, 652, 653, 655, 656, 657, 658, 659, 662, 663, 664, 665, 666, 667, 668, 670, 672, 674, 675, 676, 678, 681, 682, 683, 685, 686, 687, 690, 691, 694, 699, 702, 703, 707, 708, 709, 711, 712, 713, 714, 716, 719, 720, 721, 722, 723, 724, 725, 727, 728, 732, 733, 734, 735, 736, 737, 738, 739, 740, 741, 742, 743, 744, 745, 746, 747, 748, 749, 750, 753, 754, 755, 756, 757, 758, 759, 760, 761, 762, 764, 765, 766, 767, 768, 769, 770, 771, 773, 774, 775, 776, 777, 778, 779, 780, 781, 782, 783, 785, 786, 787, 788, 789, 790, 791, 792, 793, 794, 795, 796, 797, 798, 799, 800, 801, 802, 803, 804, 805, 807, 809, 810, 811, 813, 814, 816, 817, 818, 819, 820, 821, 822, 823, 824, 825, 829, 831, 832, 833, 834, 836, 837, 839, 840],
-                    vendors_li_consent: vec![],
+                    vendors_consent: vec![1, 2, 4, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 18, 20, 21, 22, 23, 24, 25, 26, 27, 28, 30, 31, 32, 33, 34, 36, 37, 39, 40, 41, 42, 44, 45, 47, 49, 50, 51, 52, 53, 57, 58, 59, 60, 61, 62, 63, 65, 66, 67, 68, 69, 70, 71, 72, 73, 76, 77, 78, 79, 80, 82, 83, 84, 85, 86, 88, 89, 90, 91, 92, 94, 95, 97, 98, 100, 101, 102, 104, 108, 109, 110, 111, 114, 115, 119, 120, 122, 124, 126, 127, 128, 129, 130, 131, 132, 133, 134, 136, 137, 138, 139, 140, 141, 142, 143, 144, 145, 147, 149, 150, 152, 153, 154, 155, 157, 158, 159, 160, 161, 162, 163, 164, 165, 167, 168, 173, 174, 177, 178, 179, 183, 184, 185, 192, 193, 194, 195, 199, 200, 202, 203, 205, 206, 209, 210, 211, 212, 213, 215, 216, 217, 218, 223, 224, 226, 227, 228, 231, 234, 235, 236, 238, 239, 240, 241, 242, 243, 244, 246, 248, 249, 250, 251, 252, 253, 254, 255, 256, 259, 261, 262, 263, 264, 265, 270, 272, 273, 274, 275, 277, 279, 280, 281, 282, 284, 285, 289, 290, 293, 294, 297, 299, 301, 302, 303, 304, 310, 311, 312, 314, 315, 316, 317, 318, 319, 321, 323, 325, 328, 329, 331, 333, 335, 336, 337, 343, 345, 347, 349, 350, 351, 354, 358, 359, 360, 361, 368, 371, 373, 374, 375, 377, 378, 380, 381, 382, 385, 387, 388, 394, 402, 408, 409, 410, 412, 413, 416, 418, 422, 423, 424, 427, 428, 429, 434, 435, 436, 438, 439, 440, 444, 447, 448, 450, 455, 458, 459, 462, 467, 468, 475, 479, 482, 486, 488, 490, 491, 493, 495, 498, 501, 502, 505, 507, 508, 509, 511, 512, 516, 517, 519, 520, 521, 524, 527, 528, 530, 531, 535, 536, 539, 541, 543, 545, 546, 547, 549, 550, 553, 554, 556, 559, 561, 565, 568, 569, 570, 571, 573, 574, 577, 579, 580, 584, 587, 591, 593, 596, 598, 601, 602, 606, 607, 609, 610, 613, 614, 617, 618, 620, 621, 624, 625, 626, 628, 630, 631, 638, 639, 644, 645, 646, 647, 648, 649, 650, 652, 653, 655, 656, 657, 658, 659, 662, 663, 664, 665, 666, 667, 668, 670, 672, 674, 675, 676, 678, 681, 682, 683, 685, 686, 687, 690, 691, 694, 699, 702, 703, 707, 708, 709, 711, 712, 713, 714, 716, 719, 720, 721, 722, 723, 724, 725, 727, 728, 732, 733, 734, 735, 736, 737, 738, 739, 740, 741, 742, 743, 744, 745, 746, 747, 748, 749, 750, 753, 754, 755, 756, 757, 758, 759, 760, 761, 762, 764, 765, 766, 767, 768, 769, 770, 771, 773, 774, 775, 776, 777, 778, 779, 780, 781, 782, 783, 785, 786, 787, 788, 789, 790, 791, 792, 793, 794, 795, 796, 797, 798, 799, 800, 801, 802, 803, 804, 805, 807, 809, 810, 811, 813, 814, 816, 817, 818, 819, 820, 821, 822, 823, 824, 825, 829, 831, 832, 833, 834, 836, 837, 839, 840].into(),
+                    vendors_li_consent: vec![].into(),
                     publisher_restrictions: vec![
                         PublisherRestriction {
                             purpose_id: 2,
                             restriction_type: PublisherRestrictionType::RequireConsent,
-                            vendor_list: vec![21, 32, 52, 76, 79, 142, 183, 253, 559, 755],
+                            vendor_list: vec![21, 32, 52, 76, 79, 142, 183, 253, 559, 755].into(),
                         },
                         PublisherRestriction {
                             purpose_id: 5,
                             restriction_type: PublisherRestrictionType::RequireConsent,
-                            vendor_list: vec![76, 142, 755],
+                            vendor_list: vec![76, 142, 755].into(),
                         },
                         PublisherRestriction {
                             purpose_id: 6,
                             restriction_type: PublisherRestrictionType::RequireConsent,
-                            vendor_list: vec![76, 142, 755],
+                            vendor_list: vec![76, 142, 755].into(),
                         },
                         PublisherRestriction {
                             purpose_id: 7,
                             restriction_type: PublisherRestrictionType::RequireConsent,
-                            vendor_list: vec![21, 32, 52, 76, 79, 142, 152, 183, 253, 278, 559, 755, 792],
+                            vendor_list: vec![21, 32, 52, 76, 79, 142, 152, 183, 253, 278, 559, 755, 792].into(),
                         },
                         PublisherRestriction {
                             purpose_id: 9,
                             restriction_type: PublisherRestrictionType::RequireConsent,
-                            vendor_list: vec![76, 142, 183, 559, 755],
+                            vendor_list: vec![76, 142, 183, 559, 755].into(),
                         },
                         PublisherRestriction {
                             purpose_id: 10,
                             restriction_type: PublisherRestrictionType::RequireConsent,
-                            vendor_list: vec![21, 32, 52, 76, 79, 183, 253, 278, 559, 755, 792],
+                            vendor_list: vec![21, 32, 52, 76, 79, 183, 253, 278, 559, 755, 792].into(),
                         },
                         PublisherRestriction {
                             purpose_id: 8,
                             restriction_type: PublisherRestrictionType::RequireConsent,
-                            vendor_list: vec![76, 142],
+                            vendor_list: vec![76, 142].into(),
                         },
-                    ],
-                    disclosed_vendors: vec![],
-                    allowed_vendors: vec![],
+                    ].into(),
+                    disclosed_vendors: vec![].into(),
+                    allowed_vendors: vec![].into(),
                     publisher_purposes_consent: (1..11).collect(),
                     publisher_purposes_li_transparency: vec![],
                     custom_purposes_consent: vec![1, 2],
@@ -577,22 +878,22 @@ mod tests {
                 purposes_li_transparency: vec![12, 13, 14, 15, 16, 17, 18],
                 purpose_one_treatment: true,
                 publisher_country_code: String::from("AA"),
-                vendors_consent: vec![2, 3, 4, 5],
-                vendors_li_consent: vec![1, 2, 3, 4],
+                vendors_consent: vec![2, 3, 4, 5].into(),
+                vendors_li_consent: vec![1, 2, 3, 4].into(),
                 publisher_restrictions: vec![
                     PublisherRestriction {
                         purpose_id: 1,
                         restriction_type: PublisherRestrictionType::RequireConsent,
-                        vendor_list: vec![1, 2, 3, 4, 5, 6, 7],
+                        vendor_list: vec![1, 2, 3, 4, 5, 6, 7].into(),
                     },
                     PublisherRestriction {
                         purpose_id: 2,
                         restriction_type: PublisherRestrictionType::RequireConsent,
-                        vendor_list: vec![8, 9, 10, 11, 12, 13],
+                        vendor_list: vec![8, 9, 10, 11, 12, 13].into(),
                     },
-                ],
-                disclosed_vendors: vec![1, 2, 3, 4, 5, 6, 19, 20, 21, 22, 23, 25, 27, 28, 29, 30],
-                allowed_vendors: vec![1, 2, 3, 4, 5, 6, 19, 20, 21, 22, 23, 25, 27, 28, 29, 30],
+                ].into(),
+                disclosed_vendors: vec![1, 2, 3, 4, 5, 6, 19, 20, 21, 22, 23, 25, 27, 28, 29, 30].into(),
+                allowed_vendors: vec![1, 2, 3, 4, 5, 6, 19, 20, 21, 22, 23, 25, 27, 28, 29, 30].into(),
                 publisher_purposes_consent: vec![1, 13, 24],
                 publisher_purposes_li_transparency: vec![1, 2, 3],
                 custom_purposes_consent: vec![2, 3, 4, 19, 20, 21, 22, 23],
@@ -621,42 +922,42 @@ mod tests {
                 purposes_li_transparency: vec![],
                 purpose_one_treatment: false,
                 publisher_country_code: String::from("DE"),
-                vendors_consent: vec![40, 122, 141, 174, 211, 228, 373, 511, 565, 577, 647, 699, 735, 748, 765],
-                vendors_li_consent: vec![],
+                vendors_consent: vec![40, 122, 141, 174, 211, 228, 373, 511, 565, 577, 647, 699, 735, 748, 765].into(),
+                vendors_li_consent: vec![].into(),
                 publisher_restrictions: vec![
                     PublisherRestriction {
                         purpose_id: 2,
                         restriction_type: PublisherRestrictionType::RequireConsent,
-                        vendor_list: vec![755],
+                        vendor_list: vec![755].into(),
                     },
                     PublisherRestriction {
                         purpose_id: 5,
                         restriction_type: PublisherRestrictionType::RequireConsent,
-                        vendor_list: vec![755],
+                        vendor_list: vec![755].into(),
                     },
                     PublisherRestriction {
                         purpose_id: 6,
                         restriction_type: PublisherRestrictionType::RequireConsent,
-                        vendor_list: vec![755],
+                        vendor_list: vec![755].into(),
                     },
                     PublisherRestriction {
                         purpose_id: 7,
                         restriction_type: PublisherRestrictionType::RequireConsent,
-                        vendor_list: vec![755],
+                        vendor_list: vec![755].into(),
                     },
                     PublisherRestriction {
                         purpose_id: 9,
                         restriction_type: PublisherRestrictionType::RequireConsent,
-                        vendor_list: vec![755],
+                        vendor_list: vec![755].into(),
                     },
                     PublisherRestriction {
                         purpose_id: 10,
                         restriction_type: PublisherRestrictionType::RequireConsent,
-                        vendor_list: vec![755],
+                        vendor_list: vec![755].into(),
                     },
-                ],
-                disclosed_vendors: vec![],
-                allowed_vendors: vec![],
+                ].into(),
+                disclosed_vendors: vec![].into(),
+                allowed_vendors: vec![].into(),
                 publisher_purposes_consent: (1..11).collect(),
                 publisher_purposes_li_transparency: vec![],
                 custom_purposes_consent: vec![],
@@ -685,11 +986,11 @@ mod tests {
                 purposes_li_transparency: vec![2, 3, 4, 5, 6, 7, 8, 9, 10],
                 purpose_one_treatment: false,
                 publisher_country_code: String::from("EU"),
-                vendors_consent: vec![6, 25, 39, 42, 44, 50, 68, 126, 136, 140, 141, 152, 278, 312, 371, 412, 522, 602, 730, 755, 785, 820, 831],
-                vendors_li_consent: vec![6, 25, 39, 42, 44, 50, 68, 126, 136, 140, 141, 152, 278, 312, 371, 412, 522, 602, 730, 755, 785, 820, 831],
-                publisher_restrictions: vec![],
-                disclosed_vendors: vec![],
-                allowed_vendors: vec![],
+                vendors_consent: vec![6, 25, 39, 42, 44, 50, 68, 126, 136, 140, 141, 152, 278, 312, 371, 412, 522, 602, 730, 755, 785, 820, 831].into(),
+                vendors_li_consent: vec![6, 25, 39, 42, 44, 50, 68, 126, 136, 140, 141, 152, 278, 312, 371, 412, 522, 602, 730, 755, 785, 820, 831].into(),
+                publisher_restrictions: vec![].into(),
+                disclosed_vendors: vec![].into(),
+                allowed_vendors: vec![].into(),
                 publisher_purposes_consent: vec![],
                 publisher_purposes_li_transparency: vec![],
                 custom_purposes_consent: vec![],
@@ -697,4 +998,325 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn iab_tcf_v2_malformed_segments() {
+        for tc_string in [
+            "COvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAA",
+            "COvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAAAEEUACCKAAA.IA",
+            "COvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAAAEEUACCKAAA.YA",
+            "COvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAAAEEUACCKAAA.YAAAAAAAB_AA",
+        ] {
+            assert_eq!(
+                TcModelV2::try_from(tc_string),
+                Err(TcsError::InsufficientLength),
+                "{}",
+                tc_string
+            );
+        }
+    }
+
+    #[test]
+    fn iab_tcf_v2_error_locations() {
+        let vendor_range = vendor_segment(&[(5, 3)], 0);
+        let padding = vendor_segment(&[(3, 5)], 1);
+        let duplicate_segment = vendor_segment(&[(3, 5)], 0);
+        let duplicate_segment = format!(
+            "{}.{}",
+            duplicate_segment,
+            duplicate_segment.split('.').nth(1).unwrap()
+        );
+        let restricted = TcModelV2 {
+            consent_language: String::from("EN"),
+            publisher_country_code: String::from("AA"),
+            publisher_restrictions: vec![PublisherRestriction {
+                purpose_id: 1,
+                restriction_type: PublisherRestrictionType::Undefined,
+                vendor_list: vec![2].into(),
+            }]
+            .into(),
+            ..TcModelV2::default()
+        }
+        .encode()
+        .unwrap();
+
+        for (tc_string, err) in [
+            (
+                "COvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAA",
+                TcsError::InsufficientLength.at(location(SegmentType::Core, "MaxVendorId", 213)),
+            ),
+            (
+                "COvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAA",
+                TcsError::InsufficientLength.at(location(
+                    SegmentType::Core,
+                    "PurposeOneTreatment",
+                    200,
+                )),
+            ),
+            (
+                "COvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAAAEEUACCKAAA.IA",
+                TcsError::InsufficientLength.at(location(
+                    SegmentType::DisclosedVendors,
+                    "MaxVendorId",
+                    3,
+                )),
+            ),
+            (
+                "COvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAAAEEUACCKAAA.YA",
+                TcsError::InsufficientLength.at(location(
+                    SegmentType::PublisherTc,
+                    "PubPurposesConsent",
+                    3,
+                )),
+            ),
+            (
+                "COvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAAAEEUACCKAAA.YAAAAAAAB_AA",
+                TcsError::InsufficientLength.at(location(
+                    SegmentType::PublisherTc,
+                    "CustomPurposesLITransparency",
+                    72,
+                )),
+            ),
+            (
+                "COvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAAAEEUACCKAAA.4A",
+                TcsError::InvalidSegmentDefinition.at(ErrorLocation::new("SegmentType", 0)),
+            ),
+            (
+                vendor_range.as_str(),
+                TcsError::InvalidVendorRange.at(location(
+                    SegmentType::DisclosedVendors,
+                    "EndVendorId",
+                    49,
+                )),
+            ),
+            (
+                padding.as_str(),
+                TcsError::NonZeroPadding.at(ErrorLocation {
+                    segment: Some(SegmentType::DisclosedVendors),
+                    field: None,
+                    bit: 65,
+                }),
+            ),
+            (
+                duplicate_segment.as_str(),
+                TcsError::DuplicateSegment.at(location(
+                    SegmentType::DisclosedVendors,
+                    "SegmentType",
+                    0,
+                )),
+            ),
+            (
+                restricted.as_str(),
+                TcsError::InvalidRestrictionType.at(location(
+                    SegmentType::Core,
+                    "RestrictionType",
+                    265,
+                )),
+            ),
+        ] {
+            assert_eq!(
+                TcModelV2::decode_with_location(tc_string, &STRICT),
+                Err(err),
+                "{}",
+                tc_string
+            );
+        }
+    }
+
+    #[test]
+    fn iab_tcf_v2_vendor_range_max_vendor_id() {
+        assert_eq!(
+            TcModelV2::try_from("CAAAAAAAAAAAAAAAAAENAAAAAAAAAAAAAAAH__wAf__f_-AAEAAAAA")
+                .map(|tc_model| tc_model.vendors_consent),
+            Ok(vec![65534, 65535].into())
+        );
+    }
+
+    #[test]
+    fn iab_tcf_v2_from_segments() {
+        let tc_string = "COw4XqLOw4XqLAAAAAENAXCf-v-gAAAfwIAAACngAI8AEFABgACAA4A.IAPPwAPrwA.QAPPwAPrwA.cAEAPAAAC7gAHw4AAA";
+        let tcs_segments: Vec<Vec<u8>> = tc_string
+            .split('.')
+            .map(|segment| BASE64_ENGINE.decode(segment).unwrap())
+            .collect();
+        let segments: Vec<&[u8]> = tcs_segments.iter().map(Vec::as_slice).collect();
+        let tc_model = TcModelV2::try_from(tc_string).unwrap();
+
+        assert_eq!(TcModelV2::from_segments(&segments), Ok(tc_model.clone()));
+        assert_eq!(
+            TcModelV2::try_from(segments[0]),
+            Ok(TcModelV2 {
+                disclosed_vendors: VendorSet::new(),
+                allowed_vendors: VendorSet::new(),
+                publisher_purposes_consent: vec![],
+                publisher_purposes_li_transparency: vec![],
+                custom_purposes_consent: vec![],
+                custom_purposes_li_transparency: vec![],
+                ..tc_model
+            })
+        );
+        assert_eq!(
+            TcModelV2::from_segments(&[]),
+            Err(TcsError::InsufficientLength)
+        );
+    }
+
+    #[test]
+    fn iab_tcf_v2_single_segment() {
+        let tc_model = TcModelV2::try_from(
+            "COw4XqLOw4XqLAAAAAENAXCf-v-gAAAfwIAAACngAI8AEFABgACAA4A.IAPPwAPrwA.cAEAPAAAC7gAHw4AAA",
+        )
+        .unwrap();
+        let disclosed_vendors = Segment::try_from("IAPPwAPrwA").unwrap();
+        let publisher_tc = Segment::try_from("cAEAPAAAC7gAHw4AAA").unwrap();
+
+        assert_eq!(
+            disclosed_vendors.segment_type(),
+            SegmentType::DisclosedVendors
+        );
+        assert_eq!(
+            disclosed_vendors,
+            Segment::DisclosedVendors(tc_model.disclosed_vendors)
+        );
+        assert_eq!(
+            publisher_tc,
+            Segment::PublisherTc(PublisherTc {
+                publisher_purposes_consent: tc_model.publisher_purposes_consent,
+                publisher_purposes_li_transparency: tc_model.publisher_purposes_li_transparency,
+                custom_purposes_consent: tc_model.custom_purposes_consent,
+                custom_purposes_li_transparency: tc_model.custom_purposes_li_transparency,
+            })
+        );
+        assert_eq!(
+            Segment::try_from("COw4XqLOw4XqLAAAAAENAXCf-v-gAAAfwIAAACngAI8AEFABgACAA4A"),
+            Err(TcsError::InvalidSegmentDefinition)
+        );
+        assert_eq!(Segment::try_from("YA"), Err(TcsError::InsufficientLength));
+    }
+
+    fn location(segment: SegmentType, field: &'static str, bit: usize) -> ErrorLocation {
+        ErrorLocation {
+            segment: Some(segment),
+            ..ErrorLocation::new(field, bit)
+        }
+    }
+
+    const STRICT: DecodeOptions = DecodeOptions { strict: true };
+    const CORE_SEGMENT: &str = "COvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAAAEEUACCKAAA";
+
+    fn vendor_segment(ranges: &[(u16, u16)], padding: u64) -> String {
+        let mut writer = BitWriter::default();
+
+        writer.push(1, 3).unwrap();
+        writer.push(10, 16).unwrap();
+        writer.push_bool(true);
+        writer.push_vendor_ranges(ranges).unwrap();
+        writer.push(padding, 2).unwrap();
+
+        format!(
+            "{}.{}",
+            CORE_SEGMENT,
+            BASE64_ENGINE.encode(writer.into_bytes())
+        )
+    }
+
+    #[test]
+    fn iab_tcf_v2_strict_fixtures() {
+        for tc_string in TC_STRINGS {
+            assert!(TcModelV2::try_from(*tc_string).is_ok());
+            assert_eq!(
+                TcModelV2::decode_with_options(tc_string, &STRICT),
+                TcModelV2::try_from(*tc_string),
+                "{}",
+                tc_string
+            );
+        }
+    }
+
+    #[test]
+    fn iab_tcf_v2_strict_vendor_range() {
+        let tc_string = vendor_segment(&[(5, 3)], 0);
+
+        assert_eq!(
+            TcModelV2::try_from(tc_string.as_str()).map(|tc_model| tc_model.disclosed_vendors),
+            Ok(VendorSet::new())
+        );
+        assert_eq!(
+            TcModelV2::decode_with_options(&tc_string, &STRICT),
+            Err(TcsError::InvalidVendorRange)
+        );
+    }
+
+    #[test]
+    fn iab_tcf_v2_strict_padding() {
+        let tc_string = vendor_segment(&[(3, 5)], 1);
+
+        assert_eq!(
+            TcModelV2::try_from(tc_string.as_str()).map(|tc_model| tc_model.disclosed_vendors),
+            Ok(vec![3, 4, 5].into())
+        );
+        assert_eq!(
+            TcModelV2::decode_with_options(&tc_string, &STRICT),
+            Err(TcsError::NonZeroPadding)
+        );
+        assert!(TcModelV2::decode_with_options(&vendor_segment(&[(3, 5)], 0), &STRICT).is_ok());
+    }
+
+    #[test]
+    fn iab_tcf_v2_strict_duplicate_segment() {
+        let tc_string = vendor_segment(&[(3, 5)], 0);
+        let tc_string = format!("{}.{}", tc_string, tc_string.split('.').nth(1).unwrap());
+
+        assert!(TcModelV2::try_from(tc_string.as_str()).is_ok());
+        assert_eq!(
+            TcModelV2::decode_with_options(&tc_string, &STRICT),
+            Err(TcsError::DuplicateSegment)
+        );
+    }
+
+    #[test]
+    fn iab_tcf_v2_strict_core_values() {
+        let tc_model = TcModelV2 {
+            created_at: 1000,
+            updated_at: 1000,
+            consent_language: String::from("EN"),
+            tcf_policy_version: 2,
+            publisher_country_code: String::from("AA"),
+            ..TcModelV2::default()
+        };
+        let restricted = TcModelV2 {
+            publisher_restrictions: vec![PublisherRestriction {
+                purpose_id: 1,
+                restriction_type: PublisherRestrictionType::Undefined,
+                vendor_list: vec![2].into(),
+            }]
+            .into(),
+            ..tc_model.clone()
+        };
+        let updated_before_created = TcModelV2 {
+            created_at: 2000,
+            ..tc_model.clone()
+        };
+        let unknown_policy_version = TcModelV2 {
+            tcf_policy_version: 7,
+            ..tc_model.clone()
+        };
+
+        for (tc_model, err) in [
+            (restricted, TcsError::InvalidRestrictionType),
+            (updated_before_created, TcsError::InvalidTimestamps),
+            (
+                unknown_policy_version,
+                TcsError::UnsupportedPolicyVersion(7),
+            ),
+        ] {
+            let tc_string = tc_model.encode().unwrap();
+
+            assert_eq!(TcModelV2::try_from(tc_string.as_str()), Ok(tc_model));
+            assert_eq!(
+                TcModelV2::decode_with_options(&tc_string, &STRICT),
+                Err(err)
+            );
+        }
+    }
 }
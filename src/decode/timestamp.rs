@@ -0,0 +1,165 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::decode::model::{TcModelV1, TcModelV2};
+
+fn system_time_from_millis(epoch_millis: u64) -> Option<SystemTime> {
+    UNIX_EPOCH.checked_add(Duration::from_millis(epoch_millis))
+}
+
+#[cfg(feature = "chrono")]
+fn chrono_date_time_from_millis(epoch_millis: u64) -> Option<chrono::DateTime<chrono::Utc>> {
+    chrono::DateTime::from_timestamp_millis(i64::try_from(epoch_millis).ok()?)
+}
+
+#[cfg(feature = "time")]
+fn offset_date_time_from_millis(epoch_millis: u64) -> Option<time::OffsetDateTime> {
+    time::OffsetDateTime::from_unix_timestamp_nanos(i128::from(epoch_millis) * 1_000_000).ok()
+}
+
+macro_rules! impl_timestamps {
+    ($model: ty) => {
+        impl $model {
+            /// Returns `created_at` as [`SystemTime`], `None` if it can't be represented on this platform
+            ///
+            /// [`SystemTime`]: https://doc.rust-lang.org/std/time/struct.SystemTime.html
+            pub fn created(&self) -> Option<SystemTime> {
+                system_time_from_millis(self.created_at)
+            }
+
+            /// Returns `updated_at` as [`SystemTime`], `None` if it can't be represented on this platform
+            ///
+            /// [`SystemTime`]: https://doc.rust-lang.org/std/time/struct.SystemTime.html
+            pub fn last_updated(&self) -> Option<SystemTime> {
+                system_time_from_millis(self.updated_at)
+            }
+
+            /// Returns the time which passed between the last update and `now`, zero if `now` is before the last update
+            pub fn age_at(&self, now: SystemTime) -> Duration {
+                self.last_updated()
+                    .and_then(|last_updated| now.duration_since(last_updated).ok())
+                    .unwrap_or_default()
+            }
+
+            /// Returns `true` if the last update is more than `duration` ago
+            pub fn is_older_than(&self, duration: Duration) -> bool {
+                self.age_at(SystemTime::now()) > duration
+            }
+
+            /// Returns `created_at` as [`chrono::DateTime`], `None` if it's out of range (requires the `chrono` feature)
+            ///
+            /// [`chrono::DateTime`]: https://docs.rs/chrono/0.4/chrono/struct.DateTime.html
+            #[cfg(feature = "chrono")]
+            pub fn created_date_time(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+                chrono_date_time_from_millis(self.created_at)
+            }
+
+            /// Returns `updated_at` as [`chrono::DateTime`], `None` if it's out of range (requires the `chrono` feature)
+            ///
+            /// [`chrono::DateTime`]: https://docs.rs/chrono/0.4/chrono/struct.DateTime.html
+            #[cfg(feature = "chrono")]
+            pub fn last_updated_date_time(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+                chrono_date_time_from_millis(self.updated_at)
+            }
+
+            /// Returns `created_at` as [`time::OffsetDateTime`] in UTC, `None` if it's out of range (requires the `time` feature)
+            ///
+            /// [`time::OffsetDateTime`]: https://docs.rs/time/0.3/time/struct.OffsetDateTime.html
+            #[cfg(feature = "time")]
+            pub fn created_offset_date_time(&self) -> Option<time::OffsetDateTime> {
+                offset_date_time_from_millis(self.created_at)
+            }
+
+            /// Returns `updated_at` as [`time::OffsetDateTime`] in UTC, `None` if it's out of range (requires the `time` feature)
+            ///
+            /// [`time::OffsetDateTime`]: https://docs.rs/time/0.3/time/struct.OffsetDateTime.html
+            #[cfg(feature = "time")]
+            pub fn last_updated_offset_date_time(&self) -> Option<time::OffsetDateTime> {
+                offset_date_time_from_millis(self.updated_at)
+            }
+        }
+    };
+}
+
+impl_timestamps!(TcModelV1);
+impl_timestamps!(TcModelV2);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tc_model_timestamps() {
+        let tc_model = TcModelV2 {
+            created_at: 1582243059300,
+            updated_at: 1582243060300,
+            ..TcModelV2::default()
+        };
+
+        assert_eq!(
+            tc_model.created(),
+            Some(UNIX_EPOCH + Duration::from_millis(1582243059300))
+        );
+        assert_eq!(
+            tc_model.age_at(UNIX_EPOCH + Duration::from_millis(1582243070300)),
+            Duration::from_secs(10)
+        );
+        assert_eq!(tc_model.age_at(UNIX_EPOCH), Duration::ZERO);
+        assert!(tc_model.is_older_than(Duration::from_secs(60 * 60 * 24 * 395)));
+        assert!(!TcModelV1 {
+            updated_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_millis() as u64,
+            ..TcModelV1::default()
+        }
+        .is_older_than(Duration::from_secs(60)));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn tc_model_chrono_timestamps() {
+        let tc_model = TcModelV2 {
+            created_at: 1582243059300,
+            ..TcModelV2::default()
+        };
+
+        assert_eq!(
+            tc_model
+                .created_date_time()
+                .map(|date_time| date_time.to_rfc3339()),
+            Some(String::from("2020-02-20T23:57:39.300+00:00"))
+        );
+        assert_eq!(
+            TcModelV2 {
+                created_at: u64::MAX,
+                ..TcModelV2::default()
+            }
+            .created_date_time(),
+            None
+        );
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn tc_model_time_timestamps() {
+        let tc_model = TcModelV2 {
+            updated_at: 1582243059300,
+            ..TcModelV2::default()
+        };
+
+        assert_eq!(
+            tc_model
+                .last_updated_offset_date_time()
+                .map(|offset_date_time| offset_date_time.unix_timestamp()),
+            Some(1582243059)
+        );
+        assert_eq!(
+            TcModelV2 {
+                updated_at: u64::MAX,
+                ..TcModelV2::default()
+            }
+            .last_updated_offset_date_time(),
+            None
+        );
+    }
+}
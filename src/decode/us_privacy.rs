@@ -0,0 +1,121 @@
+use std::{
+    convert::TryFrom,
+    fmt::{Display, Formatter},
+};
+
+use crate::decode::{
+    error::TcsError,
+    model::{UsPrivacy, UsPrivacyFlag},
+};
+
+fn parse_flag(val: char) -> Result<UsPrivacyFlag, TcsError> {
+    match val {
+        'Y' => Ok(UsPrivacyFlag::Yes),
+        'N' => Ok(UsPrivacyFlag::No),
+        '-' => Ok(UsPrivacyFlag::NotApplicable),
+        _ => Err(TcsError::InvalidFormat),
+    }
+}
+
+impl TryFrom<&str> for UsPrivacy {
+    type Error = TcsError;
+
+    fn try_from(val: &str) -> Result<Self, Self::Error> {
+        let characters: Vec<char> = val.chars().collect();
+
+        if let [version, explicit_notice, opt_out_sale, lspa_covered] = characters[..] {
+            let version = version.to_digit(10).ok_or(TcsError::InvalidFormat)? as u8;
+
+            if version != 1 {
+                return Err(TcsError::UnsupportedVersion(version));
+            }
+
+            Ok(Self {
+                version,
+                explicit_notice: parse_flag(explicit_notice)?,
+                opt_out_sale: parse_flag(opt_out_sale)?,
+                lspa_covered: parse_flag(lspa_covered)?,
+            })
+        } else {
+            Err(TcsError::InvalidFormat)
+        }
+    }
+}
+
+impl Display for UsPrivacyFlag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UsPrivacyFlag::Yes => write!(f, "Y"),
+            UsPrivacyFlag::No => write!(f, "N"),
+            UsPrivacyFlag::NotApplicable => write!(f, "-"),
+        }
+    }
+}
+
+impl Display for UsPrivacy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{}{}{}",
+            self.version, self.explicit_notice, self.opt_out_sale, self.lspa_covered
+        )
+    }
+}
+
+impl UsPrivacy {
+    /// Returns `true` if explicit notice and the opportunity to opt out was provided
+    pub fn is_notice_given(&self) -> bool {
+        self.explicit_notice == UsPrivacyFlag::Yes
+    }
+
+    /// Returns `true` if the user has opted out of the sale of personal data
+    pub fn has_opted_out_of_sale(&self) -> bool {
+        self.opt_out_sale == UsPrivacyFlag::Yes
+    }
+
+    /// Returns `true` if the publisher is a signatory to the IAB Limited Service Provider Agreement
+    pub fn is_lspa_covered(&self) -> bool {
+        self.lspa_covered == UsPrivacyFlag::Yes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn us_privacy() {
+        let us_privacy = UsPrivacy::try_from("1YNN").unwrap();
+
+        assert_eq!(
+            us_privacy,
+            UsPrivacy {
+                version: 1,
+                explicit_notice: UsPrivacyFlag::Yes,
+                opt_out_sale: UsPrivacyFlag::No,
+                lspa_covered: UsPrivacyFlag::No,
+            }
+        );
+        assert!(us_privacy.is_notice_given());
+        assert!(!us_privacy.has_opted_out_of_sale());
+        assert!(!us_privacy.is_lspa_covered());
+        assert_eq!(us_privacy.to_string(), "1YNN");
+        assert_eq!(
+            UsPrivacy::try_from("1---").map(|us_privacy| us_privacy.to_string()),
+            Ok(String::from("1---"))
+        );
+    }
+
+    #[test]
+    fn us_privacy_invalid() {
+        assert_eq!(
+            UsPrivacy::try_from("2YNN"),
+            Err(TcsError::UnsupportedVersion(2))
+        );
+        assert_eq!(UsPrivacy::try_from("1YN"), Err(TcsError::InvalidFormat));
+        assert_eq!(UsPrivacy::try_from("1YNNY"), Err(TcsError::InvalidFormat));
+        assert_eq!(UsPrivacy::try_from("1YXN"), Err(TcsError::InvalidFormat));
+        assert_eq!(UsPrivacy::try_from("xYNN"), Err(TcsError::InvalidFormat));
+        assert_eq!(UsPrivacy::try_from("1ynn"), Err(TcsError::InvalidFormat));
+    }
+}
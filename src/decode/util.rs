@@ -1,8 +1,69 @@
+use base64::Engine;
+
 use crate::decode::{
-    error::TcsError,
-    model::{RangeSection, RangeSectionType},
+    error::{ErrorLocation, LocatedError, SegmentType, TcsError},
+    model::{DecodeOptions, RangeSection, RangeSectionType, VendorSet},
 };
 
+pub(crate) const BASE64_ENGINE: base64::engine::general_purpose::GeneralPurpose =
+    base64::engine::general_purpose::GeneralPurpose::new(
+        &base64::alphabet::URL_SAFE,
+        base64::engine::general_purpose::GeneralPurposeConfig::new()
+            .with_encode_padding(false)
+            .with_decode_padding_mode(base64::engine::DecodePaddingMode::RequireNone),
+    );
+
+pub(crate) fn decode_segments(val: &str) -> Result<Vec<Vec<u8>>, TcsError> {
+    let mut tcs_segments: Vec<Vec<u8>> = Vec::with_capacity(4);
+
+    decode_segments_into(val, &mut tcs_segments)?;
+
+    Ok(tcs_segments)
+}
+
+/// Decodes the segments into the given buffers which are reused, returns the number of decoded segments
+pub(crate) fn decode_segments_into(
+    val: &str,
+    tcs_segments: &mut Vec<Vec<u8>>,
+) -> Result<usize, TcsError> {
+    let mut segment_count = 0;
+
+    for base64_str in val.split('.') {
+        if base64_str.is_empty() {
+            return Err(TcsError::InsufficientLength);
+        }
+
+        if segment_count == tcs_segments.len() {
+            tcs_segments.push(Vec::new());
+        }
+
+        let segment = &mut tcs_segments[segment_count];
+
+        segment.clear();
+        BASE64_ENGINE
+            .decode_vec(base64_str, segment)
+            .map_err(TcsError::InvalidUrlSafeBase64)?;
+        segment_count += 1;
+    }
+
+    Ok(segment_count)
+}
+
+pub(crate) fn parse_version_from_segments<S: AsRef<[u8]>>(val: &[S]) -> Result<u8, LocatedError> {
+    let core_segment = val.first().map_or(&[][..], |segment| segment.as_ref());
+
+    byte_list_bit_boundary_check!(
+        core_segment,
+        6,
+        ErrorLocation {
+            segment: Some(SegmentType::Core),
+            ..ErrorLocation::new("Version", 0)
+        }
+    );
+
+    Ok(parse_from_bytes(core_segment, 0, 6) as u8)
+}
+
 pub(crate) fn parse_from_bytes(val: &[u8], absolute_start_bit: usize, bit_length: usize) -> u64 {
     let first_byte_start_bit = (absolute_start_bit % 8) as u8;
     let relative_end_bit = bit_length - 1;
@@ -41,8 +102,13 @@ pub(crate) fn parse_string_from_bytes(
     bit_start: usize,
     bit_width: usize,
     char_count: usize,
-) -> Result<String, TcsError> {
-    byte_list_bit_boundary_check!(val, bit_start + (char_count * bit_width));
+    field: &'static str,
+) -> Result<String, LocatedError> {
+    byte_list_bit_boundary_check!(
+        val,
+        bit_start + (char_count * bit_width),
+        ErrorLocation::new(field, bit_start)
+    );
 
     let mut result = String::with_capacity(char_count);
     let mut offset = 0;
@@ -51,7 +117,7 @@ pub(crate) fn parse_string_from_bytes(
         let alphabet_offset = parse_from_bytes(val, bit_start + offset, bit_width) as u8;
 
         if alphabet_offset > 25 {
-            return Err(TcsError::InvalidAlphabetOffset);
+            return Err(TcsError::InvalidAlphabetOffset.at(ErrorLocation::new(field, bit_start)));
         }
 
         result.push((b'A' + alphabet_offset) as char);
@@ -64,32 +130,49 @@ pub(crate) fn parse_string_from_bytes(
 pub(crate) fn parse_vendor_range_from_bytes(
     val: &[u8],
     bit_start: usize,
-    value_type: &dyn Fn(Vec<u16>) -> RangeSectionType,
-) -> Result<RangeSection, TcsError> {
+    value_type: &dyn Fn(VendorSet) -> RangeSectionType,
+    options: &DecodeOptions,
+) -> Result<RangeSection, LocatedError> {
     let mut bit_index = bit_start + 12;
 
-    byte_list_bit_boundary_check!(val, bit_index);
+    byte_list_bit_boundary_check!(val, bit_index, ErrorLocation::new("NumEntries", bit_start));
 
     let num_entries = parse_from_bytes(val, bit_start, 12) as u16;
-    let mut entry_list: Vec<u16> = Vec::new();
+    let mut entry_list = VendorSet::new();
     let mut count = 0u16;
 
     while count < num_entries {
+        byte_list_bit_boundary_check!(
+            val,
+            bit_index + 1,
+            ErrorLocation::new("IsARange", bit_index)
+        );
+        byte_list_bit_boundary_check!(
+            val,
+            bit_index + 17,
+            ErrorLocation::new("StartOrOnlyVendorId", bit_index + 1)
+        );
+
         if parse_from_bytes(val, bit_index, 1) as u8 == 1 {
-            byte_list_bit_boundary_check!(val, bit_index + 33);
+            byte_list_bit_boundary_check!(
+                val,
+                bit_index + 33,
+                ErrorLocation::new("EndVendorId", bit_index + 17)
+            );
 
             let start_vendor_id = parse_from_bytes(val, bit_index + 1, 16) as u16;
             let end_vendor_id = parse_from_bytes(val, bit_index + 17, 16) as u16;
 
-            for vendor_id in start_vendor_id..end_vendor_id + 1 {
-                entry_list.push(vendor_id);
+            if options.strict && start_vendor_id > end_vendor_id {
+                return Err(TcsError::InvalidVendorRange
+                    .at(ErrorLocation::new("EndVendorId", bit_index + 17)));
             }
 
+            entry_list.insert_range(start_vendor_id..=end_vendor_id);
+
             bit_index += 33;
         } else {
-            byte_list_bit_boundary_check!(val, bit_index + 17);
-
-            entry_list.push(parse_from_bytes(val, bit_index + 1, 16) as u16);
+            entry_list.insert(parse_from_bytes(val, bit_index + 1, 16) as u16);
             bit_index += 17;
         }
 
@@ -102,5 +185,75 @@ pub(crate) fn parse_vendor_range_from_bytes(
     })
 }
 
+pub(crate) fn check_padding(val: &[u8], bit_start: usize) -> Result<(), LocatedError> {
+    let byte_index = bit_start / 8;
+    let is_zero_padded = val
+        .get(byte_index)
+        .is_none_or(|byte| byte & (0xff >> (bit_start % 8)) == 0)
+        && val.iter().skip(byte_index + 1).all(|byte| *byte == 0);
+
+    if is_zero_padded {
+        Ok(())
+    } else {
+        Err(TcsError::NonZeroPadding.at(ErrorLocation {
+            bit: bit_start,
+            ..ErrorLocation::default()
+        }))
+    }
+}
+
+/// Fixed-length fields at the start of a segment as `(field, bit length)`, in the order in which they are encoded
+pub(crate) struct FieldTable(pub(crate) &'static [(&'static str, usize)]);
+
+impl FieldTable {
+    /// Returns the bit offset at which the fixed-length fields end
+    pub(crate) const fn bit_end(&self) -> usize {
+        let mut bit_end = 0;
+        let mut index = 0;
+
+        while index < self.0.len() {
+            bit_end += self.0[index].1;
+            index += 1;
+        }
+
+        bit_end
+    }
+
+    /// Checks that every field is contained in the segment, reports the first field which isn't
+    pub(crate) fn check_length(&self, val: &[u8]) -> Result<(), LocatedError> {
+        let bit_length = val.len() * 8;
+        let mut field_bit = 0;
+
+        for (field, field_length) in self.0 {
+            if field_bit + field_length > bit_length {
+                return Err(TcsError::InsufficientLength.at(ErrorLocation::new(field, field_bit)));
+            }
+
+            field_bit += field_length;
+        }
+
+        Ok(())
+    }
+}
+
+pub(crate) fn parse_vendor_bitfield_from_bytes(
+    val: &[u8],
+    bit_start: usize,
+    bit_length: usize,
+) -> Result<VendorSet, LocatedError> {
+    let bit_end = bit_start + bit_length;
+
+    byte_list_bit_boundary_check!(val, bit_end, ErrorLocation::new("BitField", bit_start));
+
+    let mut vendor_set = VendorSet::new();
+
+    for bit_index in bit_start..bit_end {
+        if parse_from_bytes(val, bit_index, 1) == 1 {
+            vendor_set.insert(((bit_index - bit_start) + 1) as u16);
+        }
+    }
+
+    Ok(vendor_set)
+}
+
 parse_bitfield_from_bytes!(parse_u8_bitfield_from_bytes, u8);
-parse_bitfield_from_bytes!(parse_u16_bitfield_from_bytes, u16);
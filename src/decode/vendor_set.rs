@@ -0,0 +1,309 @@
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Formatter},
+    iter::FromIterator,
+    ops::RangeInclusive,
+};
+
+use crate::decode::model::{VendorSet, VendorSetIter};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+impl VendorSet {
+    /// Creates an empty `VendorSet`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of vendor IDs in the set
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the set contains no vendor IDs
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the set contains the given vendor ID
+    pub fn contains(&self, vendor_id: u16) -> bool {
+        let vendor_id = vendor_id as usize;
+
+        self.words
+            .get(vendor_id / WORD_BITS)
+            .is_some_and(|word| word & (1 << (vendor_id % WORD_BITS)) != 0)
+    }
+
+    /// Returns the highest vendor ID of the set
+    pub fn max_vendor_id(&self) -> Option<u16> {
+        self.words.last().map(|word| {
+            ((self.words.len() - 1) * WORD_BITS + (WORD_BITS - 1 - word.leading_zeros() as usize))
+                as u16
+        })
+    }
+
+    /// Adds the given vendor ID, returns `true` if it was not present before
+    pub fn insert(&mut self, vendor_id: u16) -> bool {
+        let vendor_id = vendor_id as usize;
+
+        self.grow(vendor_id);
+
+        let word = &mut self.words[vendor_id / WORD_BITS];
+        let bit_mask = 1 << (vendor_id % WORD_BITS);
+        let is_new = *word & bit_mask == 0;
+
+        *word |= bit_mask;
+        self.len += is_new as usize;
+
+        is_new
+    }
+
+    /// Adds all vendor IDs of the given range
+    pub fn insert_range(&mut self, range: RangeInclusive<u16>) {
+        let (start, end) = (*range.start() as usize, *range.end() as usize);
+
+        if start > end {
+            return;
+        }
+
+        self.grow(end);
+
+        for word_index in (start / WORD_BITS)..=(end / WORD_BITS) {
+            let low_bit = if word_index == start / WORD_BITS {
+                start % WORD_BITS
+            } else {
+                0
+            };
+            let high_bit = if word_index == end / WORD_BITS {
+                end % WORD_BITS
+            } else {
+                WORD_BITS - 1
+            };
+            let bit_mask = (u64::MAX >> (WORD_BITS - 1 - high_bit)) & (u64::MAX << low_bit);
+
+            self.len += (bit_mask & !self.words[word_index]).count_ones() as usize;
+            self.words[word_index] |= bit_mask;
+        }
+    }
+
+    /// Removes the given vendor ID, returns `true` if it was present before
+    pub fn remove(&mut self, vendor_id: u16) -> bool {
+        let vendor_id = vendor_id as usize;
+        let bit_mask = 1 << (vendor_id % WORD_BITS);
+
+        match self.words.get_mut(vendor_id / WORD_BITS) {
+            Some(word) if *word & bit_mask != 0 => {
+                *word &= !bit_mask;
+                self.len -= 1;
+                self.trim();
+
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns an iterator over the vendor IDs in ascending order
+    pub fn iter(&self) -> VendorSetIter<'_> {
+        VendorSetIter {
+            words: &self.words,
+            word_index: 0,
+            word: self.words.first().copied().unwrap_or_default(),
+        }
+    }
+
+    /// Returns a new set which contains the vendor IDs of both sets
+    pub fn union(&self, other: &Self) -> Self {
+        let (longer, shorter) = if self.words.len() >= other.words.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let mut words = longer.words.clone();
+
+        for (word, other_word) in words.iter_mut().zip(shorter.words.iter()) {
+            *word |= other_word;
+        }
+
+        Self::from_words(words)
+    }
+
+    /// Returns a new set which only contains the vendor IDs present in both sets
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self::from_words(
+            self.words
+                .iter()
+                .zip(other.words.iter())
+                .map(|(word, other_word)| word & other_word)
+                .collect(),
+        )
+    }
+
+    fn from_words(words: Vec<u64>) -> Self {
+        let mut vendor_set = Self {
+            len: words.iter().map(|word| word.count_ones() as usize).sum(),
+            words,
+        };
+
+        vendor_set.trim();
+        vendor_set
+    }
+
+    fn grow(&mut self, vendor_id: usize) {
+        let word_count = vendor_id / WORD_BITS + 1;
+
+        if self.words.len() < word_count {
+            self.words.resize(word_count, 0);
+        }
+    }
+
+    fn trim(&mut self) {
+        while self.words.last() == Some(&0) {
+            self.words.pop();
+        }
+    }
+}
+
+impl Iterator for VendorSetIter<'_> {
+    type Item = u16;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.word == 0 {
+            self.word_index += 1;
+            self.word = *self.words.get(self.word_index)?;
+        }
+
+        let bit_index = self.word.trailing_zeros() as usize;
+
+        self.word &= self.word - 1;
+
+        Some((self.word_index * WORD_BITS + bit_index) as u16)
+    }
+}
+
+impl<'a> IntoIterator for &'a VendorSet {
+    type Item = u16;
+    type IntoIter = VendorSetIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl FromIterator<u16> for VendorSet {
+    fn from_iter<T: IntoIterator<Item = u16>>(iter: T) -> Self {
+        let mut vendor_set = Self::new();
+
+        vendor_set.extend(iter);
+        vendor_set
+    }
+}
+
+impl Extend<u16> for VendorSet {
+    fn extend<T: IntoIterator<Item = u16>>(&mut self, iter: T) {
+        for vendor_id in iter {
+            self.insert(vendor_id);
+        }
+    }
+}
+
+impl From<Vec<u16>> for VendorSet {
+    fn from(val: Vec<u16>) -> Self {
+        val.into_iter().collect()
+    }
+}
+
+impl From<&[u16]> for VendorSet {
+    fn from(val: &[u16]) -> Self {
+        val.iter().copied().collect()
+    }
+}
+
+impl From<VendorSet> for Vec<u16> {
+    fn from(val: VendorSet) -> Self {
+        val.iter().collect()
+    }
+}
+
+impl Debug for VendorSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl PartialOrd for VendorSet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for VendorSet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vendor_set_insert_remove() {
+        let mut vendor_set = VendorSet::new();
+
+        assert!(vendor_set.insert(755));
+        assert!(!vendor_set.insert(755));
+        assert!(vendor_set.insert(2));
+        assert!(vendor_set.insert(u16::MAX));
+        assert!(vendor_set.contains(755));
+        assert!(!vendor_set.contains(754));
+        assert_eq!(vendor_set.len(), 3);
+        assert_eq!(vendor_set.max_vendor_id(), Some(u16::MAX));
+        assert!(vendor_set.remove(u16::MAX));
+        assert!(!vendor_set.remove(u16::MAX));
+        assert_eq!(vendor_set.max_vendor_id(), Some(755));
+        assert_eq!(vendor_set, vec![755, 2].into());
+        assert_eq!(vendor_set.iter().collect::<Vec<u16>>(), vec![2, 755]);
+    }
+
+    #[test]
+    fn vendor_set_insert_range() {
+        let mut vendor_set: VendorSet = vec![1, 70].into();
+
+        vendor_set.insert_range(60..=200);
+        #[allow(clippy::reversed_empty_ranges)]
+        vendor_set.insert_range(5..=4);
+
+        assert_eq!(vendor_set.len(), 142);
+        assert_eq!(
+            vendor_set.iter().collect::<Vec<u16>>(),
+            std::iter::once(1).chain(60..=200).collect::<Vec<u16>>()
+        );
+
+        vendor_set.insert_range(1..=u16::MAX);
+
+        assert_eq!(vendor_set.len(), u16::MAX as usize);
+        assert_eq!(vendor_set.words.len(), 1024);
+        assert!(!vendor_set.contains(0));
+    }
+
+    #[test]
+    fn vendor_set_union_intersection() {
+        let vendor_set: VendorSet = vec![2, 6, 8, 300].into();
+        let other: VendorSet = vec![6, 8, 9].into();
+
+        assert_eq!(vendor_set.union(&other), vec![2, 6, 8, 9, 300].into());
+        assert_eq!(vendor_set.intersection(&other), vec![6, 8].into());
+        assert_eq!(vendor_set.intersection(&other).words.len(), 1);
+        assert_eq!(VendorSet::new().union(&VendorSet::new()), VendorSet::new());
+    }
+
+    #[test]
+    fn vendor_set_debug_and_order() {
+        let vendor_set: VendorSet = vec![8, 2].into();
+
+        assert_eq!(format!("{:?}", vendor_set), "{2, 8}");
+        assert!(vendor_set < vec![3].into());
+        assert!(vendor_set > vec![2].into());
+    }
+}
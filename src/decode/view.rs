@@ -0,0 +1,333 @@
+use std::convert::TryFrom;
+
+use base64::{DecodeSliceError, Engine};
+
+use crate::decode::{
+    error::{ErrorLocation, LocatedError, SegmentType, TcsError},
+    model::{SegmentBuffer, TcModelV2, TcStringView, VendorSectionView, SEGMENT_BUFFER_LEN},
+    tcf_2::CORE_FIELDS,
+    util::{parse_from_bytes, BASE64_ENGINE},
+};
+
+impl SegmentBuffer {
+    fn decode(val: &str) -> Result<Self, TcsError> {
+        if val.is_empty() {
+            return Err(TcsError::InsufficientLength);
+        }
+
+        let mut bytes = [0u8; SEGMENT_BUFFER_LEN];
+
+        match BASE64_ENGINE.decode_slice(val, &mut bytes) {
+            Ok(len) => Ok(Self::Inline { bytes, len }),
+            Err(DecodeSliceError::OutputSliceTooSmall) => BASE64_ENGINE
+                .decode(val)
+                .map(Self::Heap)
+                .map_err(TcsError::InvalidUrlSafeBase64),
+            Err(DecodeSliceError::DecodeError(err)) => Err(TcsError::InvalidUrlSafeBase64(err)),
+        }
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        match self {
+            Self::Inline { bytes, len } => &bytes[..*len],
+            Self::Heap(bytes) => bytes.as_slice(),
+        }
+    }
+}
+
+impl VendorSectionView {
+    fn parse(val: &[u8], bit_start: usize) -> Result<Self, LocatedError> {
+        byte_list_bit_boundary_check!(
+            val,
+            bit_start + 16,
+            ErrorLocation::new("MaxVendorId", bit_start)
+        );
+        byte_list_bit_boundary_check!(
+            val,
+            bit_start + 17,
+            ErrorLocation::new("IsRangeEncoding", bit_start + 16)
+        );
+
+        let max_vendor_id = parse_from_bytes(val, bit_start, 16) as u16;
+
+        if parse_from_bytes(val, bit_start + 16, 1) == 0 {
+            let last_bit = bit_start + 17 + max_vendor_id as usize;
+
+            byte_list_bit_boundary_check!(
+                val,
+                last_bit,
+                ErrorLocation::new("BitField", bit_start + 17)
+            );
+
+            return Ok(Self {
+                max_vendor_id,
+                is_range_encoding: false,
+                num_entries: 0,
+                bit_start: bit_start + 17,
+                last_bit,
+            });
+        }
+
+        byte_list_bit_boundary_check!(
+            val,
+            bit_start + 29,
+            ErrorLocation::new("NumEntries", bit_start + 17)
+        );
+
+        let num_entries = parse_from_bytes(val, bit_start + 17, 12) as u16;
+        let mut bit_index = bit_start + 29;
+
+        for _ in 0..num_entries {
+            byte_list_bit_boundary_check!(
+                val,
+                bit_index + 1,
+                ErrorLocation::new("IsARange", bit_index)
+            );
+
+            byte_list_bit_boundary_check!(
+                val,
+                bit_index + 17,
+                ErrorLocation::new("StartOrOnlyVendorId", bit_index + 1)
+            );
+
+            if parse_from_bytes(val, bit_index, 1) == 1 {
+                byte_list_bit_boundary_check!(
+                    val,
+                    bit_index + 33,
+                    ErrorLocation::new("EndVendorId", bit_index + 17)
+                );
+
+                bit_index += 33;
+            } else {
+                bit_index += 17;
+            }
+        }
+
+        Ok(Self {
+            max_vendor_id,
+            is_range_encoding: true,
+            num_entries,
+            bit_start: bit_start + 29,
+            last_bit: bit_index,
+        })
+    }
+
+    fn contains(&self, val: &[u8], vendor_id: u16) -> bool {
+        if !self.is_range_encoding {
+            return vendor_id > 0
+                && vendor_id <= self.max_vendor_id
+                && parse_from_bytes(val, self.bit_start + vendor_id as usize - 1, 1) == 1;
+        }
+
+        let mut bit_index = self.bit_start;
+
+        for _ in 0..self.num_entries {
+            let start_vendor_id = parse_from_bytes(val, bit_index + 1, 16) as u16;
+
+            if parse_from_bytes(val, bit_index, 1) == 1 {
+                let end_vendor_id = parse_from_bytes(val, bit_index + 17, 16) as u16;
+
+                if (start_vendor_id..=end_vendor_id).contains(&vendor_id) {
+                    return true;
+                }
+
+                bit_index += 33;
+            } else {
+                if start_vendor_id == vendor_id {
+                    return true;
+                }
+
+                bit_index += 17;
+            }
+        }
+
+        false
+    }
+}
+
+impl<'a> TryFrom<&'a str> for TcStringView<'a> {
+    type Error = TcsError;
+
+    fn try_from(val: &'a str) -> Result<Self, Self::Error> {
+        let core_segment = SegmentBuffer::decode(val.split('.').next().unwrap_or_default())?;
+        let (vendors_consent, vendors_li_consent) = {
+            let bytes = core_segment.as_slice();
+
+            byte_list_bit_boundary_check!(
+                bytes,
+                6,
+                ErrorLocation {
+                    segment: Some(SegmentType::Core),
+                    ..ErrorLocation::new("Version", 0)
+                }
+            );
+
+            let version = parse_from_bytes(bytes, 0, 6) as u8;
+
+            if version != 2 {
+                return Err(TcsError::UnsupportedVersion(version));
+            }
+
+            CORE_FIELDS
+                .check_length(bytes)
+                .and_then(|_| VendorSectionView::parse(bytes, CORE_FIELDS.bit_end()))
+                .and_then(|vendors_consent| {
+                    VendorSectionView::parse(bytes, vendors_consent.last_bit)
+                        .map(|vendors_li_consent| (vendors_consent, vendors_li_consent))
+                })?
+        };
+
+        Ok(Self {
+            tc_string: val,
+            core_segment,
+            vendors_consent,
+            vendors_li_consent,
+        })
+    }
+}
+
+impl TcStringView<'_> {
+    /// Returns the ID of the CMP which last updated the TCString
+    pub fn cmp_id(&self) -> u16 {
+        parse_from_bytes(self.core_segment.as_slice(), 78, 12) as u16
+    }
+
+    /// Returns the version of the global vendor list which was used to create the TCString
+    pub fn vendor_list_version(&self) -> u16 {
+        parse_from_bytes(self.core_segment.as_slice(), 120, 12) as u16
+    }
+
+    /// Returns the version of the TCF policy which was used to create the TCString
+    pub fn tcf_policy_version(&self) -> u16 {
+        parse_from_bytes(self.core_segment.as_slice(), 132, 6) as u16
+    }
+
+    /// Returns `true` if the user opted in to the given special feature
+    pub fn special_feature_opt_in(&self, special_feature_id: u8) -> bool {
+        self.read_flag(140, 12, special_feature_id)
+    }
+
+    /// Returns `true` if the user consented to the given purpose
+    pub fn purpose_consent(&self, purpose_id: u8) -> bool {
+        self.read_flag(152, 24, purpose_id)
+    }
+
+    /// Returns `true` if the legitimate interest of the given purpose was established
+    pub fn purpose_li_transparency(&self, purpose_id: u8) -> bool {
+        self.read_flag(176, 24, purpose_id)
+    }
+
+    /// Returns `true` if the user consented to the given vendor
+    pub fn has_vendor_consent(&self, vendor_id: u16) -> bool {
+        self.vendors_consent
+            .contains(self.core_segment.as_slice(), vendor_id)
+    }
+
+    /// Returns `true` if the legitimate interest of the given vendor was established
+    pub fn has_vendor_legitimate_interest(&self, vendor_id: u16) -> bool {
+        self.vendors_li_consent
+            .contains(self.core_segment.as_slice(), vendor_id)
+    }
+
+    /// Decodes every field and segment of the TCString into a [`TcModelV2`]
+    ///
+    /// [`TcModelV2`]: struct.TcModelV2.html
+    pub fn to_model(&self) -> Result<TcModelV2, TcsError> {
+        TcModelV2::try_from(self.tc_string)
+    }
+
+    fn read_flag(&self, bit_start: usize, bit_length: u8, id: u8) -> bool {
+        id > 0
+            && id <= bit_length
+            && parse_from_bytes(self.core_segment.as_slice(), bit_start + id as usize - 1, 1) == 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encode::{
+        builder::TcModelV2Builder,
+        model::{EncodeOptions, VendorEncoding},
+    };
+
+    fn assert_view_matches_model(tc_string: &str) {
+        let tc_view = TcStringView::try_from(tc_string).unwrap();
+        let tc_model = tc_view.to_model().unwrap();
+
+        assert_eq!(tc_view.cmp_id(), tc_model.cmp_id);
+        assert_eq!(tc_view.vendor_list_version(), tc_model.vendor_list_version);
+        assert_eq!(tc_view.tcf_policy_version(), tc_model.tcf_policy_version);
+
+        for id in 0..=30 {
+            assert_eq!(
+                tc_view.special_feature_opt_in(id),
+                tc_model.special_feature_opt_ins.contains(&id)
+            );
+            assert_eq!(
+                tc_view.purpose_consent(id),
+                tc_model.purposes_consent.contains(&id)
+            );
+            assert_eq!(
+                tc_view.purpose_li_transparency(id),
+                tc_model.purposes_li_transparency.contains(&id)
+            );
+        }
+
+        for vendor_id in 0..=3000 {
+            assert_eq!(
+                tc_view.has_vendor_consent(vendor_id),
+                tc_model.vendors_consent.contains(vendor_id)
+            );
+            assert_eq!(
+                tc_view.has_vendor_legitimate_interest(vendor_id),
+                tc_model.vendors_li_consent.contains(vendor_id)
+            );
+        }
+    }
+
+    #[test]
+    fn tc_string_view_matches_model() {
+        for tc_string in [
+            "COvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAAAEEUACCKAAA",
+            "CGL23UdMFJzvuA9ACCENAXCEAC0AAGrAAA5YA5ht7-_d_7_vd-f-nrf4_4A4hM4JCKoK4YhmAqABgAEgAA",
+            "CO-Z5geO-Z5geAfbgBDEBECoAP_AAH_AAAigGfwFgADAAZABOACoAFgAMgAiAB-AERAIwAjQBMAEWAJwAXMAzgCCgEtALaAXmAxEBmgDPwM_gLAAGAAyACcAFQALAAZABEAD8AIiARgBGgCYAIsATgAuYBnAEFAJaAW0AvMBiIDNAGfgAA",
+            "COw4XqLOw4XqLAAAAAENAXCf-v-gAAAfwIAAACngAI8AEFABgACAA4A.IAPPwAPrwA.QAPPwAPrwA.cAEAPAAAC7gAHw4AAA",
+        ] {
+            assert_view_matches_model(tc_string);
+        }
+    }
+
+    #[test]
+    fn tc_string_view_heap_buffer() {
+        let tc_string = TcModelV2Builder::default()
+            .vendor_consent(2500)
+            .and_then(|builder| builder.vendor_legitimate_interest(2))
+            .unwrap()
+            .build()
+            .encode_with_options(&EncodeOptions {
+                vendor_encoding: VendorEncoding::BitField,
+            })
+            .unwrap();
+        let tc_view = TcStringView::try_from(tc_string.as_str()).unwrap();
+
+        assert!(matches!(tc_view.core_segment, SegmentBuffer::Heap(_)));
+        assert_view_matches_model(&tc_string);
+    }
+
+    #[test]
+    fn tc_string_view_errors() {
+        assert_eq!(
+            TcStringView::try_from("BOEFEAyOEFEAyAHABDENAI4AAAB9vABAASA").err(),
+            Some(TcsError::UnsupportedVersion(1))
+        );
+        assert_eq!(
+            TcStringView::try_from("COvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAA").err(),
+            Some(TcsError::InsufficientLength)
+        );
+        assert_eq!(
+            TcStringView::try_from("").err(),
+            Some(TcsError::InsufficientLength)
+        );
+    }
+}
@@ -0,0 +1,188 @@
+use crate::decode::model::PublisherRestrictionType;
+
+/// Changed value of a single field
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Clone, PartialOrd, Hash, Debug)]
+pub struct Change<T> {
+    /// Value before the change
+    pub old: T,
+    /// Value after the change
+    pub new: T,
+}
+
+/// IDs which were added to or removed from a list, both in ascending order
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Clone, PartialOrd, Hash, Debug)]
+pub struct IdChanges<T> {
+    /// IDs which are only present after the change (e.g. granted consent)
+    pub added: Vec<T>,
+    /// IDs which are only present before the change (e.g. revoked consent)
+    pub removed: Vec<T>,
+}
+
+/// Vendors which were added to or removed from a publisher restriction
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Clone, PartialOrd, Hash, Debug)]
+pub struct PublisherRestrictionChange {
+    /// ID of the restricted purpose
+    pub purpose_id: u8,
+    /// Type of the publisher restriction
+    pub restriction_type: PublisherRestrictionType,
+    /// Changed vendors of the publisher restriction
+    pub vendors: IdChanges<u16>,
+}
+
+/// Changes between two [`TcModelV2`], see [`TcModelV2::diff`]
+///
+/// Unchanged fields are `None` or empty and are omitted when serialized
+///
+/// [`TcModelV2`]: struct.TcModelV2.html
+/// [`TcModelV2::diff`]: struct.TcModelV2.html#method.diff
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Clone, PartialOrd, Hash, Debug, Default)]
+pub struct TcModelV2Diff {
+    /// Changed `created_at`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub created_at: Option<Change<u64>>,
+    /// Changed `updated_at`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub updated_at: Option<Change<u64>>,
+    /// Changed `cmp_id`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub cmp_id: Option<Change<u16>>,
+    /// Changed `cmp_version`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub cmp_version: Option<Change<u16>>,
+    /// Changed `consent_screen`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub consent_screen: Option<Change<u8>>,
+    /// Changed `consent_language`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub consent_language: Option<Change<String>>,
+    /// Changed `vendor_list_version`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub vendor_list_version: Option<Change<u16>>,
+    /// Changed `tcf_policy_version`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub tcf_policy_version: Option<Change<u16>>,
+    /// Changed `is_service_specific`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub is_service_specific: Option<Change<bool>>,
+    /// Changed `use_non_standard_stacks`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub use_non_standard_stacks: Option<Change<bool>>,
+    /// Changed `purpose_one_treatment`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub purpose_one_treatment: Option<Change<bool>>,
+    /// Changed `publisher_country_code`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub publisher_country_code: Option<Change<String>>,
+    /// Changed `special_feature_opt_ins`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub special_feature_opt_ins: Option<IdChanges<u8>>,
+    /// Changed `purposes_consent`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub purposes_consent: Option<IdChanges<u8>>,
+    /// Changed `purposes_li_transparency`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub purposes_li_transparency: Option<IdChanges<u8>>,
+    /// Changed `vendors_consent`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub vendors_consent: Option<IdChanges<u16>>,
+    /// Changed `vendors_li_consent`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub vendors_li_consent: Option<IdChanges<u16>>,
+    /// Changed vendors of publisher restrictions, ordered by purpose ID and restriction type
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub publisher_restrictions: Vec<PublisherRestrictionChange>,
+    /// Changed `disclosed_vendors`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub disclosed_vendors: Option<IdChanges<u16>>,
+    /// Changed `allowed_vendors`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub allowed_vendors: Option<IdChanges<u16>>,
+    /// Changed `publisher_purposes_consent`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub publisher_purposes_consent: Option<IdChanges<u8>>,
+    /// Changed `publisher_purposes_li_transparency`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub publisher_purposes_li_transparency: Option<IdChanges<u8>>,
+    /// Changed `custom_purposes_consent`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub custom_purposes_consent: Option<IdChanges<u8>>,
+    /// Changed `custom_purposes_li_transparency`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub custom_purposes_li_transparency: Option<IdChanges<u8>>,
+}
@@ -0,0 +1,251 @@
+use std::cmp::Ordering;
+
+use crate::{
+    decode::model::{PublisherRestrictionType, TcModelV2, VendorSet},
+    diff::model::{Change, IdChanges, PublisherRestrictionChange, TcModelV2Diff},
+};
+
+fn diff_value<T: PartialEq + Clone>(old: &T, new: &T) -> Option<Change<T>> {
+    if old == new {
+        return None;
+    }
+
+    Some(Change {
+        old: old.clone(),
+        new: new.clone(),
+    })
+}
+
+fn diff_ids<T: Ord + Copy>(old: &[T], new: &[T]) -> Option<IdChanges<T>> {
+    let mut added: Vec<T> = new.iter().filter(|id| !old.contains(id)).copied().collect();
+    let mut removed: Vec<T> = old.iter().filter(|id| !new.contains(id)).copied().collect();
+
+    added.sort_unstable();
+    added.dedup();
+    removed.sort_unstable();
+    removed.dedup();
+
+    if added.is_empty() && removed.is_empty() {
+        return None;
+    }
+
+    Some(IdChanges { added, removed })
+}
+
+fn diff_vendors(old: &VendorSet, new: &VendorSet) -> Option<IdChanges<u16>> {
+    if old == new {
+        return None;
+    }
+
+    Some(IdChanges {
+        added: new.iter().filter(|id| !old.contains(*id)).collect(),
+        removed: old.iter().filter(|id| !new.contains(*id)).collect(),
+    })
+}
+
+fn restricted_vendors(
+    tc_model: &TcModelV2,
+    purpose_id: u8,
+    restriction_type: &PublisherRestrictionType,
+) -> VendorSet {
+    tc_model
+        .publisher_restrictions
+        .for_purpose(purpose_id)
+        .filter(|publisher_restriction| publisher_restriction.restriction_type == *restriction_type)
+        .fold(VendorSet::new(), |vendor_set, publisher_restriction| {
+            vendor_set.union(&publisher_restriction.vendor_list)
+        })
+}
+
+fn diff_publisher_restrictions(
+    old: &TcModelV2,
+    new: &TcModelV2,
+) -> Vec<PublisherRestrictionChange> {
+    let mut restrictions: Vec<(u8, PublisherRestrictionType)> = Vec::new();
+
+    for publisher_restriction in old
+        .publisher_restrictions
+        .iter()
+        .chain(new.publisher_restrictions.iter())
+    {
+        let restriction = (
+            publisher_restriction.purpose_id,
+            publisher_restriction.restriction_type.clone(),
+        );
+
+        if !restrictions.contains(&restriction) {
+            restrictions.push(restriction);
+        }
+    }
+
+    restrictions.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    restrictions
+        .into_iter()
+        .filter_map(|(purpose_id, restriction_type)| {
+            diff_vendors(
+                &restricted_vendors(old, purpose_id, &restriction_type),
+                &restricted_vendors(new, purpose_id, &restriction_type),
+            )
+            .map(|vendors| PublisherRestrictionChange {
+                purpose_id,
+                restriction_type,
+                vendors,
+            })
+        })
+        .collect()
+}
+
+impl TcModelV2 {
+    /// Returns the changes from `self` (before) to `other` (after)
+    ///
+    /// ```rust,edition2021
+    /// use lib_tcstring::TcModelV2Builder;
+    /// let old = TcModelV2Builder::default().vendor_consent(8).unwrap().build();
+    /// let new = TcModelV2Builder::default().vendor_consent(755).unwrap().build();
+    /// let diff = old.diff(&new);
+    ///
+    /// assert_eq!(diff.vendors_consent.as_ref().map(|ids| &ids.added), Some(&vec![755]));
+    /// assert_eq!(diff.vendors_consent.as_ref().map(|ids| &ids.removed), Some(&vec![8]));
+    /// assert_eq!(diff.cmp_id, None);
+    /// ```
+    pub fn diff(&self, other: &Self) -> TcModelV2Diff {
+        TcModelV2Diff {
+            created_at: diff_value(&self.created_at, &other.created_at),
+            updated_at: diff_value(&self.updated_at, &other.updated_at),
+            cmp_id: diff_value(&self.cmp_id, &other.cmp_id),
+            cmp_version: diff_value(&self.cmp_version, &other.cmp_version),
+            consent_screen: diff_value(&self.consent_screen, &other.consent_screen),
+            consent_language: diff_value(&self.consent_language, &other.consent_language),
+            vendor_list_version: diff_value(&self.vendor_list_version, &other.vendor_list_version),
+            tcf_policy_version: diff_value(&self.tcf_policy_version, &other.tcf_policy_version),
+            is_service_specific: diff_value(&self.is_service_specific, &other.is_service_specific),
+            use_non_standard_stacks: diff_value(
+                &self.use_non_standard_stacks,
+                &other.use_non_standard_stacks,
+            ),
+            purpose_one_treatment: diff_value(
+                &self.purpose_one_treatment,
+                &other.purpose_one_treatment,
+            ),
+            publisher_country_code: diff_value(
+                &self.publisher_country_code,
+                &other.publisher_country_code,
+            ),
+            special_feature_opt_ins: diff_ids(
+                &self.special_feature_opt_ins,
+                &other.special_feature_opt_ins,
+            ),
+            purposes_consent: diff_ids(&self.purposes_consent, &other.purposes_consent),
+            purposes_li_transparency: diff_ids(
+                &self.purposes_li_transparency,
+                &other.purposes_li_transparency,
+            ),
+            vendors_consent: diff_vendors(&self.vendors_consent, &other.vendors_consent),
+            vendors_li_consent: diff_vendors(&self.vendors_li_consent, &other.vendors_li_consent),
+            publisher_restrictions: diff_publisher_restrictions(self, other),
+            disclosed_vendors: diff_vendors(&self.disclosed_vendors, &other.disclosed_vendors),
+            allowed_vendors: diff_vendors(&self.allowed_vendors, &other.allowed_vendors),
+            publisher_purposes_consent: diff_ids(
+                &self.publisher_purposes_consent,
+                &other.publisher_purposes_consent,
+            ),
+            publisher_purposes_li_transparency: diff_ids(
+                &self.publisher_purposes_li_transparency,
+                &other.publisher_purposes_li_transparency,
+            ),
+            custom_purposes_consent: diff_ids(
+                &self.custom_purposes_consent,
+                &other.custom_purposes_consent,
+            ),
+            custom_purposes_li_transparency: diff_ids(
+                &self.custom_purposes_li_transparency,
+                &other.custom_purposes_li_transparency,
+            ),
+        }
+    }
+}
+
+impl TcModelV2Diff {
+    /// Returns `true` if both models are equal
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decode::model::PublisherRestriction;
+
+    #[test]
+    fn diff_tc_models() {
+        let old = TcModelV2 {
+            updated_at: 1000,
+            cmp_id: 27,
+            purposes_consent: vec![1, 2, 3],
+            vendors_consent: vec![8, 755].into(),
+            publisher_restrictions: vec![
+                PublisherRestriction {
+                    purpose_id: 2,
+                    restriction_type: PublisherRestrictionType::RequireConsent,
+                    vendor_list: vec![1, 2].into(),
+                },
+                PublisherRestriction {
+                    purpose_id: 1,
+                    restriction_type: PublisherRestrictionType::NotAllowed,
+                    vendor_list: vec![4].into(),
+                },
+            ]
+            .into(),
+            ..TcModelV2::default()
+        };
+        let new = TcModelV2 {
+            updated_at: 2000,
+            purposes_consent: vec![1, 3, 4],
+            vendors_consent: vec![755, 1000].into(),
+            publisher_restrictions: vec![
+                PublisherRestriction {
+                    purpose_id: 1,
+                    restriction_type: PublisherRestrictionType::NotAllowed,
+                    vendor_list: vec![4].into(),
+                },
+                PublisherRestriction {
+                    purpose_id: 2,
+                    restriction_type: PublisherRestrictionType::RequireConsent,
+                    vendor_list: vec![2, 3].into(),
+                },
+            ]
+            .into(),
+            ..old.clone()
+        };
+
+        assert_eq!(
+            old.diff(&new),
+            TcModelV2Diff {
+                updated_at: Some(Change {
+                    old: 1000,
+                    new: 2000,
+                }),
+                purposes_consent: Some(IdChanges {
+                    added: vec![4],
+                    removed: vec![2],
+                }),
+                vendors_consent: Some(IdChanges {
+                    added: vec![1000],
+                    removed: vec![8],
+                }),
+                publisher_restrictions: vec![PublisherRestrictionChange {
+                    purpose_id: 2,
+                    restriction_type: PublisherRestrictionType::RequireConsent,
+                    vendors: IdChanges {
+                        added: vec![3],
+                        removed: vec![1],
+                    },
+                }],
+                ..TcModelV2Diff::default()
+            }
+        );
+        assert!(old.diff(&old).is_empty());
+        assert!(!old.diff(&new).is_empty());
+    }
+}
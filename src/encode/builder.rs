@@ -0,0 +1,566 @@
+use std::{
+    convert::TryFrom,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    decode::{
+        error::TcsError,
+        model::{
+            CountryCode, LanguageCode, PublisherRestriction, PublisherRestrictionType, TcModelV2,
+        },
+    },
+    encode::util::vendor_ranges,
+};
+
+/// Builder which only accepts values that can be encoded into a TCString
+///
+/// Every [`TcModelV2`] created by [`build`] can be encoded with [`TcModelV2::encode`]
+///
+/// The builder starts with `EN` as consent language and `AA` as publisher country code
+///
+/// ```rust,edition2021
+/// use lib_tcstring::{PublisherRestrictionType, TcModelV2Builder};
+/// # fn main() -> Result<(), lib_tcstring::TcsError> {
+/// let tc_model = TcModelV2Builder::default()
+///     .cmp_id(27)?
+///     .language("de")?
+///     .consent_purpose(1)?
+///     .vendor_consent(755)?
+///     .restrict(2, PublisherRestrictionType::RequireConsent, &[755])?
+///     .build();
+///
+/// assert_eq!(tc_model.consent_language, "DE");
+/// assert!(tc_model.encode().is_ok());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`TcModelV2`]: struct.TcModelV2.html
+/// [`TcModelV2::encode`]: struct.TcModelV2.html#method.encode
+/// [`build`]: struct.TcModelV2Builder.html#method.build
+#[derive(PartialEq, Eq, Clone, PartialOrd, Hash, Debug)]
+pub struct TcModelV2Builder {
+    tc_model: TcModelV2,
+}
+
+fn check_range(value: u64, min: u64, max: u64) -> Result<(), TcsError> {
+    if value < min || value > max {
+        return Err(TcsError::ValueOutOfRange);
+    }
+
+    Ok(())
+}
+
+fn epoch_millis(time: SystemTime) -> Result<u64, TcsError> {
+    time.duration_since(UNIX_EPOCH)
+        .ok()
+        .and_then(|duration| u64::try_from(duration.as_millis()).ok())
+        .ok_or(TcsError::ValueOutOfRange)
+}
+
+fn check_bit_length(value: u64, bit_length: usize) -> Result<(), TcsError> {
+    check_range(value, 0, (1 << bit_length) - 1)
+}
+
+fn insert_sorted<T: Ord>(list: &mut Vec<T>, value: T) {
+    if let Err(index) = list.binary_search(&value) {
+        list.insert(index, value);
+    }
+}
+
+impl Default for TcModelV2Builder {
+    fn default() -> Self {
+        Self {
+            tc_model: TcModelV2 {
+                consent_language: String::from("EN"),
+                publisher_country_code: String::from("AA"),
+                ..TcModelV2::default()
+            },
+        }
+    }
+}
+
+impl TcModelV2Builder {
+    /// Sets the epoch milliseconds when the TCString was first created
+    ///
+    /// The value is truncated to deciseconds, which is the precision of the TCString
+    pub fn created_at(mut self, epoch_millis: u64) -> Result<Self, TcsError> {
+        check_bit_length(epoch_millis / 100, 36)?;
+        self.tc_model.created_at = epoch_millis / 100 * 100;
+
+        Ok(self)
+    }
+
+    /// Sets the epoch milliseconds when the TCString was last updated
+    ///
+    /// The value is truncated to deciseconds, which is the precision of the TCString
+    pub fn updated_at(mut self, epoch_millis: u64) -> Result<Self, TcsError> {
+        check_bit_length(epoch_millis / 100, 36)?;
+        self.tc_model.updated_at = epoch_millis / 100 * 100;
+
+        Ok(self)
+    }
+
+    /// Sets the time when the TCString was first created, see [`created_at`]
+    ///
+    /// [`created_at`]: struct.TcModelV2Builder.html#method.created_at
+    pub fn created(self, time: SystemTime) -> Result<Self, TcsError> {
+        self.created_at(epoch_millis(time)?)
+    }
+
+    /// Sets the time when the TCString was last updated, see [`updated_at`]
+    ///
+    /// [`updated_at`]: struct.TcModelV2Builder.html#method.updated_at
+    pub fn last_updated(self, time: SystemTime) -> Result<Self, TcsError> {
+        self.updated_at(epoch_millis(time)?)
+    }
+
+    /// Sets the Consent Management Platform ID (`0..=4095`)
+    pub fn cmp_id(mut self, cmp_id: u16) -> Result<Self, TcsError> {
+        check_bit_length(cmp_id as u64, 12)?;
+        self.tc_model.cmp_id = cmp_id;
+
+        Ok(self)
+    }
+
+    /// Sets the Consent Management Platform version (`0..=4095`)
+    pub fn cmp_version(mut self, cmp_version: u16) -> Result<Self, TcsError> {
+        check_bit_length(cmp_version as u64, 12)?;
+        self.tc_model.cmp_version = cmp_version;
+
+        Ok(self)
+    }
+
+    /// Sets the CMP screen number at which consent was given (`0..=63`)
+    pub fn consent_screen(mut self, consent_screen: u8) -> Result<Self, TcsError> {
+        check_bit_length(consent_screen as u64, 6)?;
+        self.tc_model.consent_screen = consent_screen;
+
+        Ok(self)
+    }
+
+    /// Sets the [`ISO 639-1`] language code, see [`LanguageCode`] and [`consent_language`]
+    ///
+    /// [`ISO 639-1`]: https://en.wikipedia.org/wiki/ISO_639-1
+    /// [`LanguageCode`]: struct.LanguageCode.html
+    /// [`consent_language`]: struct.TcModelV2Builder.html#method.consent_language
+    pub fn language(self, language: &str) -> Result<Self, TcsError> {
+        Ok(self.consent_language(&LanguageCode::try_from(language)?))
+    }
+
+    /// Sets the validated consent language, only the two letter language of an extended language code is encoded
+    pub fn consent_language(mut self, language_code: &LanguageCode) -> Self {
+        self.tc_model.consent_language = language_code.language().to_string();
+
+        self
+    }
+
+    /// Sets the version of the global vendor list (`0..=4095`)
+    pub fn vendor_list_version(mut self, vendor_list_version: u16) -> Result<Self, TcsError> {
+        check_bit_length(vendor_list_version as u64, 12)?;
+        self.tc_model.vendor_list_version = vendor_list_version;
+
+        Ok(self)
+    }
+
+    /// Sets the TCF policy version (`0..=63`)
+    pub fn tcf_policy_version(mut self, tcf_policy_version: u16) -> Result<Self, TcsError> {
+        check_bit_length(tcf_policy_version as u64, 6)?;
+        self.tc_model.tcf_policy_version = tcf_policy_version;
+
+        Ok(self)
+    }
+
+    /// Sets whether the signals were stored in service-specific storage
+    pub fn service_specific(mut self, is_service_specific: bool) -> Self {
+        self.tc_model.is_service_specific = is_service_specific;
+
+        self
+    }
+
+    /// Sets whether the CMP used non-standard stack descriptions
+    pub fn non_standard_stacks(mut self, use_non_standard_stacks: bool) -> Self {
+        self.tc_model.use_non_standard_stacks = use_non_standard_stacks;
+
+        self
+    }
+
+    /// Sets whether "Purpose 1" was not disclosed
+    pub fn purpose_one_treatment(mut self, purpose_one_treatment: bool) -> Self {
+        self.tc_model.purpose_one_treatment = purpose_one_treatment;
+
+        self
+    }
+
+    /// Sets the [`ISO 3166-1 alpha-2`] publisher country code, see [`CountryCode`] and [`publisher_country`]
+    ///
+    /// [`ISO 3166-1 alpha-2`]: https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2
+    /// [`CountryCode`]: struct.CountryCode.html
+    /// [`publisher_country`]: struct.TcModelV2Builder.html#method.publisher_country
+    pub fn publisher_country_code(self, country_code: &str) -> Result<Self, TcsError> {
+        Ok(self.publisher_country(&CountryCode::try_from(country_code)?))
+    }
+
+    /// Sets the validated publisher country code
+    pub fn publisher_country(mut self, country_code: &CountryCode) -> Self {
+        self.tc_model.publisher_country_code = country_code.as_str().to_string();
+
+        self
+    }
+
+    /// Adds an opted-in "Special Feature" (`1..=12`)
+    pub fn special_feature_opt_in(mut self, special_feature_id: u8) -> Result<Self, TcsError> {
+        check_range(special_feature_id as u64, 1, 12)?;
+        insert_sorted(
+            &mut self.tc_model.special_feature_opt_ins,
+            special_feature_id,
+        );
+
+        Ok(self)
+    }
+
+    /// Adds an allowed purpose (`1..=24`)
+    pub fn consent_purpose(mut self, purpose_id: u8) -> Result<Self, TcsError> {
+        check_range(purpose_id as u64, 1, 24)?;
+        insert_sorted(&mut self.tc_model.purposes_consent, purpose_id);
+
+        Ok(self)
+    }
+
+    /// Adds an allowed "Legitimate Interest" purpose (`1..=24`)
+    pub fn legitimate_interest_purpose(mut self, purpose_id: u8) -> Result<Self, TcsError> {
+        check_range(purpose_id as u64, 1, 24)?;
+        insert_sorted(&mut self.tc_model.purposes_li_transparency, purpose_id);
+
+        Ok(self)
+    }
+
+    /// Adds an allowed vendor (`1..=65535`)
+    pub fn vendor_consent(mut self, vendor_id: u16) -> Result<Self, TcsError> {
+        check_range(vendor_id as u64, 1, u16::MAX as u64)?;
+        self.tc_model.vendors_consent.insert(vendor_id);
+
+        Ok(self)
+    }
+
+    /// Adds a vendor "Legitimate Interest" disclosure (`1..=65535`)
+    pub fn vendor_legitimate_interest(mut self, vendor_id: u16) -> Result<Self, TcsError> {
+        check_range(vendor_id as u64, 1, u16::MAX as u64)?;
+        self.tc_model.vendors_li_consent.insert(vendor_id);
+
+        Ok(self)
+    }
+
+    /// Adds a publisher restriction for the given purpose (`1..=24`) and vendors (`1..=65535`)
+    ///
+    /// Vendors are merged into an existing restriction with the same purpose and restriction type,
+    /// the merged vendors must fit into at most `4095` ranges
+    ///
+    /// [`PublisherRestrictionType::Undefined`] is rejected since it can't be used, as well as vendors
+    /// which already have a different restriction type for the same purpose
+    ///
+    /// [`PublisherRestrictionType::Undefined`]: enum.PublisherRestrictionType.html#variant.Undefined
+    pub fn restrict(
+        mut self,
+        purpose_id: u8,
+        restriction_type: PublisherRestrictionType,
+        vendor_list: &[u16],
+    ) -> Result<Self, TcsError> {
+        check_range(purpose_id as u64, 1, 24)?;
+
+        if restriction_type == PublisherRestrictionType::Undefined || vendor_list.contains(&0) {
+            return Err(TcsError::ValueOutOfRange);
+        }
+
+        let publisher_restrictions = &mut self.tc_model.publisher_restrictions;
+        let has_conflicting_restriction = publisher_restrictions
+            .for_purpose(purpose_id)
+            .filter(|publisher_restriction| {
+                publisher_restriction.restriction_type != restriction_type
+            })
+            .any(|publisher_restriction| {
+                vendor_list
+                    .iter()
+                    .any(|vendor_id| publisher_restriction.vendor_list.contains(*vendor_id))
+            });
+
+        if has_conflicting_restriction {
+            return Err(TcsError::ValueOutOfRange);
+        }
+
+        let existing_restriction =
+            publisher_restrictions
+                .iter_mut()
+                .find(|publisher_restriction| {
+                    publisher_restriction.purpose_id == purpose_id
+                        && publisher_restriction.restriction_type == restriction_type
+                });
+        let mut merged_vendor_list = existing_restriction
+            .as_ref()
+            .map(|publisher_restriction| publisher_restriction.vendor_list.clone())
+            .unwrap_or_default();
+
+        merged_vendor_list.extend(vendor_list.iter().copied());
+        // vendors of a restriction are always range encoded with a 12 bit `NumEntries` field
+        check_bit_length(vendor_ranges(&merged_vendor_list)?.len() as u64, 12)?;
+
+        match existing_restriction {
+            Some(publisher_restriction) => publisher_restriction.vendor_list = merged_vendor_list,
+            None => publisher_restrictions.push(PublisherRestriction {
+                purpose_id,
+                restriction_type,
+                vendor_list: merged_vendor_list,
+            }),
+        }
+
+        Ok(self)
+    }
+
+    /// Adds a vendor which has been disclosed to the user (`1..=65535`)
+    pub fn disclosed_vendor(mut self, vendor_id: u16) -> Result<Self, TcsError> {
+        check_range(vendor_id as u64, 1, u16::MAX as u64)?;
+        self.tc_model.disclosed_vendors.insert(vendor_id);
+
+        Ok(self)
+    }
+
+    /// Adds a vendor the publisher permits using OOB legal bases (`1..=65535`)
+    pub fn allowed_vendor(mut self, vendor_id: u16) -> Result<Self, TcsError> {
+        check_range(vendor_id as u64, 1, u16::MAX as u64)?;
+        self.tc_model.allowed_vendors.insert(vendor_id);
+
+        Ok(self)
+    }
+
+    /// Adds a purpose which is established on the legal basis of consent for the publisher (`1..=24`)
+    pub fn publisher_consent_purpose(mut self, purpose_id: u8) -> Result<Self, TcsError> {
+        check_range(purpose_id as u64, 1, 24)?;
+        insert_sorted(&mut self.tc_model.publisher_purposes_consent, purpose_id);
+
+        Ok(self)
+    }
+
+    /// Adds a purpose which is established on the legal basis of "Legitimate Interest" for the publisher (`1..=24`)
+    pub fn publisher_legitimate_interest_purpose(
+        mut self,
+        purpose_id: u8,
+    ) -> Result<Self, TcsError> {
+        check_range(purpose_id as u64, 1, 24)?;
+        insert_sorted(
+            &mut self.tc_model.publisher_purposes_li_transparency,
+            purpose_id,
+        );
+
+        Ok(self)
+    }
+
+    /// Adds an allowed custom purpose for the publisher (`1..=63`)
+    pub fn custom_consent_purpose(mut self, purpose_id: u8) -> Result<Self, TcsError> {
+        check_range(purpose_id as u64, 1, 63)?;
+        insert_sorted(&mut self.tc_model.custom_purposes_consent, purpose_id);
+
+        Ok(self)
+    }
+
+    /// Adds a custom purpose which is established on the legal basis of "Legitimate Interest" (`1..=63`)
+    pub fn custom_legitimate_interest_purpose(mut self, purpose_id: u8) -> Result<Self, TcsError> {
+        check_range(purpose_id as u64, 1, 63)?;
+        insert_sorted(
+            &mut self.tc_model.custom_purposes_li_transparency,
+            purpose_id,
+        );
+
+        Ok(self)
+    }
+
+    /// Returns the built [`TcModelV2`]
+    ///
+    /// [`TcModelV2`]: struct.TcModelV2.html
+    pub fn build(self) -> TcModelV2 {
+        self.tc_model
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{convert::TryFrom, time::Duration};
+
+    use super::*;
+
+    #[test]
+    fn builder_round_trip() {
+        let tc_model = TcModelV2Builder::default()
+            .created_at(1582243059312)
+            .and_then(|builder| builder.updated_at(1582243059300))
+            .and_then(|builder| builder.cmp_id(27))
+            .and_then(|builder| builder.vendor_list_version(15))
+            .and_then(|builder| builder.tcf_policy_version(2))
+            .and_then(|builder| builder.language("en"))
+            .and_then(|builder| builder.consent_purpose(3))
+            .and_then(|builder| builder.consent_purpose(1))
+            .and_then(|builder| builder.consent_purpose(2))
+            .and_then(|builder| builder.vendor_consent(8))
+            .and_then(|builder| builder.vendor_consent(2))
+            .and_then(|builder| builder.vendor_consent(6))
+            .and_then(|builder| builder.vendor_legitimate_interest(2))
+            .and_then(|builder| builder.restrict(2, PublisherRestrictionType::NotAllowed, &[8, 2]))
+            .and_then(|builder| builder.restrict(2, PublisherRestrictionType::NotAllowed, &[6]))
+            .and_then(|builder| builder.custom_consent_purpose(2))
+            .unwrap()
+            .build();
+
+        assert_eq!(tc_model.created_at, 1582243059300);
+        assert_eq!(tc_model.consent_language, "EN");
+        assert_eq!(tc_model.publisher_country_code, "AA");
+        assert_eq!(tc_model.purposes_consent, vec![1, 2, 3]);
+        assert_eq!(tc_model.vendors_consent, vec![2, 6, 8].into());
+        assert_eq!(
+            tc_model.publisher_restrictions,
+            vec![PublisherRestriction {
+                purpose_id: 2,
+                restriction_type: PublisherRestrictionType::NotAllowed,
+                vendor_list: vec![2, 6, 8].into(),
+            }]
+            .into()
+        );
+        assert_eq!(
+            TcModelV2::try_from(tc_model.encode().unwrap().as_str()),
+            Ok(tc_model)
+        );
+    }
+
+    #[test]
+    fn builder_iso_codes() {
+        let tc_model = TcModelV2Builder::default()
+            .consent_language(&"sr-Latn".parse().unwrap())
+            .publisher_country(&"de".parse().unwrap())
+            .build();
+
+        assert_eq!(tc_model.consent_language, "SR");
+        assert_eq!(tc_model.publisher_country_code, "DE");
+        assert_eq!(
+            TcModelV2::try_from(tc_model.encode().unwrap().as_str()),
+            Ok(tc_model)
+        );
+    }
+
+    #[test]
+    fn builder_system_time() {
+        let tc_model = TcModelV2Builder::default()
+            .created(UNIX_EPOCH + Duration::from_millis(1582243059312))
+            .and_then(|builder| builder.last_updated(UNIX_EPOCH + Duration::from_secs(1582243060)))
+            .unwrap()
+            .build();
+
+        assert_eq!(tc_model.created_at, 1582243059300);
+        assert_eq!(tc_model.updated_at, 1582243060000);
+        assert_eq!(
+            TcModelV2Builder::default().created(UNIX_EPOCH - Duration::from_millis(1)),
+            Err(TcsError::ValueOutOfRange)
+        );
+        assert_eq!(
+            TcModelV2Builder::default().last_updated(UNIX_EPOCH + Duration::from_millis(100 << 36)),
+            Err(TcsError::ValueOutOfRange)
+        );
+    }
+
+    #[test]
+    fn builder_rejects_values_which_cant_be_encoded() {
+        let builder = TcModelV2Builder::default();
+
+        assert_eq!(
+            builder.clone().consent_purpose(25),
+            Err(TcsError::ValueOutOfRange)
+        );
+        assert_eq!(
+            builder.clone().consent_purpose(0),
+            Err(TcsError::ValueOutOfRange)
+        );
+        assert_eq!(
+            builder.clone().special_feature_opt_in(13),
+            Err(TcsError::ValueOutOfRange)
+        );
+        assert_eq!(
+            builder.clone().vendor_consent(0),
+            Err(TcsError::ValueOutOfRange)
+        );
+        assert_eq!(builder.clone().cmp_id(4096), Err(TcsError::ValueOutOfRange));
+        assert_eq!(builder.clone().language("d3"), Err(TcsError::InvalidFormat));
+        assert_eq!(
+            builder.clone().publisher_country_code("DEU"),
+            Err(TcsError::InvalidFormat)
+        );
+        assert_eq!(
+            builder.clone().publisher_country_code("HL"),
+            Err(TcsError::InvalidFormat)
+        );
+        assert_eq!(
+            builder
+                .clone()
+                .restrict(1, PublisherRestrictionType::Undefined, &[1]),
+            Err(TcsError::ValueOutOfRange)
+        );
+
+        let vendor_list: Vec<u16> = (0..4096).map(|index| index * 2 + 1).collect();
+
+        assert_eq!(
+            builder.clone().restrict(
+                1,
+                PublisherRestrictionType::NotAllowed,
+                &vendor_list[..4096]
+            ),
+            Err(TcsError::ValueOutOfRange)
+        );
+        assert_eq!(
+            builder
+                .clone()
+                .restrict(
+                    1,
+                    PublisherRestrictionType::NotAllowed,
+                    &vendor_list[..4095]
+                )
+                .and_then(|builder| builder.restrict(
+                    1,
+                    PublisherRestrictionType::NotAllowed,
+                    &vendor_list[4095..]
+                )),
+            Err(TcsError::ValueOutOfRange)
+        );
+        assert!(builder
+            .restrict(
+                1,
+                PublisherRestrictionType::NotAllowed,
+                &vendor_list[..4095]
+            )
+            .unwrap()
+            .build()
+            .encode()
+            .is_ok());
+    }
+
+    #[test]
+    fn builder_rejects_conflicting_restriction_types() {
+        let builder = TcModelV2Builder::default()
+            .restrict(2, PublisherRestrictionType::NotAllowed, &[8, 2])
+            .unwrap();
+
+        assert_eq!(
+            builder
+                .clone()
+                .restrict(2, PublisherRestrictionType::RequireConsent, &[6, 8]),
+            Err(TcsError::ValueOutOfRange)
+        );
+        assert_eq!(
+            builder
+                .restrict(2, PublisherRestrictionType::RequireConsent, &[6])
+                .and_then(|builder| builder.restrict(
+                    3,
+                    PublisherRestrictionType::RequireConsent,
+                    &[8]
+                ))
+                .map(|builder| builder.build().publisher_restrictions.len()),
+            Ok(3)
+        );
+    }
+}
@@ -0,0 +1,4 @@
+pub mod builder;
+pub mod model;
+pub mod tcf_2;
+pub(crate) mod util;
@@ -0,0 +1,25 @@
+/// Encoding of the vendor sections (`VendorsConsent`, `VendorsLIConsent`, "Disclosed Vendors" and "Allowed Vendors")
+/// as defined in [`Vendor Consent String Format V2 Core String`]
+///
+/// [`Vendor Consent String Format V2 Core String`]: https://github.com/InteractiveAdvertisingBureau/GDPR-Transparency-and-Consent-Framework/blob/81a3b9ed1545148be380b4408e6361cd2294446d/TCFv2/IAB%20Tech%20Lab%20-%20Consent%20string%20and%20vendor%20list%20formats%20v2.md#the-core-string
+#[derive(PartialEq, Eq, Clone, Copy, PartialOrd, Hash, Debug, Default)]
+pub enum VendorEncoding {
+    /// Uses whichever encoding results in fewer bits for each vendor section
+    #[default]
+    Auto,
+    /// Always encodes vendor sections as bitfield
+    BitField,
+    /// Always encodes vendor sections as list of vendor ranges
+    Range,
+}
+
+/// Options which are used by [`TcModelV2::encode_with_options`]
+///
+/// [`TcModelV2::encode_with_options`]: struct.TcModelV2.html#method.encode_with_options
+#[derive(PartialEq, Eq, Clone, PartialOrd, Hash, Debug, Default)]
+pub struct EncodeOptions {
+    /// Encoding of the vendor sections, see [`VendorEncoding`] for more details
+    ///
+    /// [`VendorEncoding`]: enum.VendorEncoding.html
+    pub vendor_encoding: VendorEncoding,
+}
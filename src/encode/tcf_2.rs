@@ -0,0 +1,302 @@
+use base64::Engine;
+
+use crate::{
+    decode::{
+        error::TcsError,
+        model::{PublisherRestrictionType, PublisherRestrictions, TcModelV2, VendorSet},
+        util::BASE64_ENGINE,
+    },
+    encode::{
+        model::{EncodeOptions, VendorEncoding},
+        util::{max_vendor_id, vendor_range_bit_length, vendor_ranges, BitWriter},
+    },
+};
+
+fn encode_vendor_section(
+    writer: &mut BitWriter,
+    vendor_list: &VendorSet,
+    options: &EncodeOptions,
+) -> Result<(), TcsError> {
+    let max_vendor_id = max_vendor_id(vendor_list);
+    let ranges = vendor_ranges(vendor_list)?;
+    let is_range_encoding = match options.vendor_encoding {
+        VendorEncoding::Auto => vendor_range_bit_length(&ranges) < max_vendor_id as usize,
+        VendorEncoding::BitField => false,
+        VendorEncoding::Range => true,
+    };
+
+    writer.push(max_vendor_id as u64, 16)?;
+    writer.push_bool(is_range_encoding);
+
+    if is_range_encoding {
+        writer.push_vendor_ranges(&ranges)
+    } else {
+        writer.push_vendor_bitfield(vendor_list, max_vendor_id as usize);
+
+        Ok(())
+    }
+}
+
+fn encode_publisher_restrictions(
+    writer: &mut BitWriter,
+    publisher_restrictions: &PublisherRestrictions,
+) -> Result<(), TcsError> {
+    writer.push(publisher_restrictions.len() as u64, 12)?;
+
+    for publisher_restriction in publisher_restrictions {
+        writer.push(publisher_restriction.purpose_id as u64, 6)?;
+        writer.push(
+            match publisher_restriction.restriction_type {
+                PublisherRestrictionType::NotAllowed => 0,
+                PublisherRestrictionType::RequireConsent => 1,
+                PublisherRestrictionType::RequireLegitimateInterest => 2,
+                PublisherRestrictionType::Undefined => 3,
+            },
+            2,
+        )?;
+        writer.push_vendor_range(&publisher_restriction.vendor_list)?;
+    }
+
+    Ok(())
+}
+
+fn encode_core_segment(val: &TcModelV2, options: &EncodeOptions) -> Result<Vec<u8>, TcsError> {
+    let mut writer = BitWriter::default();
+
+    writer.push(2, 6)?;
+    writer.push(val.created_at / 100, 36)?;
+    writer.push(val.updated_at / 100, 36)?;
+    writer.push(val.cmp_id as u64, 12)?;
+    writer.push(val.cmp_version as u64, 12)?;
+    writer.push(val.consent_screen as u64, 6)?;
+    writer.push_string(&val.consent_language, 6, 2)?;
+    writer.push(val.vendor_list_version as u64, 12)?;
+    writer.push(val.tcf_policy_version as u64, 6)?;
+    writer.push_bool(val.is_service_specific);
+    writer.push_bool(val.use_non_standard_stacks);
+    writer.push_bitfield(&val.special_feature_opt_ins, 12)?;
+    writer.push_bitfield(&val.purposes_consent, 24)?;
+    writer.push_bitfield(&val.purposes_li_transparency, 24)?;
+    writer.push_bool(val.purpose_one_treatment);
+    writer.push_string(&val.publisher_country_code, 6, 2)?;
+    encode_vendor_section(&mut writer, &val.vendors_consent, options)?;
+    encode_vendor_section(&mut writer, &val.vendors_li_consent, options)?;
+    encode_publisher_restrictions(&mut writer, &val.publisher_restrictions)?;
+
+    Ok(writer.into_bytes())
+}
+
+fn encode_vendor_segment(
+    segment_type: u64,
+    vendor_list: &VendorSet,
+    options: &EncodeOptions,
+) -> Result<Vec<u8>, TcsError> {
+    let mut writer = BitWriter::default();
+
+    writer.push(segment_type, 3)?;
+    encode_vendor_section(&mut writer, vendor_list, options)?;
+
+    Ok(writer.into_bytes())
+}
+
+fn encode_publisher_tc_segment(val: &TcModelV2) -> Result<Vec<u8>, TcsError> {
+    let mut writer = BitWriter::default();
+    let custom_purposes_count = val
+        .custom_purposes_consent
+        .iter()
+        .chain(val.custom_purposes_li_transparency.iter())
+        .copied()
+        .max()
+        .unwrap_or_default() as usize;
+
+    writer.push(3, 3)?;
+    writer.push_bitfield(&val.publisher_purposes_consent, 24)?;
+    writer.push_bitfield(&val.publisher_purposes_li_transparency, 24)?;
+    writer.push(custom_purposes_count as u64, 6)?;
+    writer.push_bitfield(&val.custom_purposes_consent, custom_purposes_count)?;
+    writer.push_bitfield(&val.custom_purposes_li_transparency, custom_purposes_count)?;
+
+    Ok(writer.into_bytes())
+}
+
+impl TcModelV2 {
+    /// Encodes the model into a TCString using the default [`EncodeOptions`]
+    ///
+    /// The "Disclosed Vendors", "Allowed Vendors" and "Publisher TC" segments are only appended if they contain any values
+    ///
+    /// ```rust,edition2021
+    /// use std::convert::TryFrom;
+    /// let tc_model = lib_tcstring::TcModelV2::try_from("COvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAAAEEUACCKAAA").unwrap();
+    /// // will return a Result which contains either the TCString or an Error
+    /// // if a value of the TcModel can't be represented in the TCString
+    /// let tc_string = tc_model.encode();
+    /// ```
+    ///
+    /// [`EncodeOptions`]: struct.EncodeOptions.html
+    pub fn encode(&self) -> Result<String, TcsError> {
+        self.encode_with_options(&EncodeOptions::default())
+    }
+
+    /// Encodes the model into a TCString using the given [`EncodeOptions`]
+    ///
+    /// ```rust,edition2021
+    /// use std::convert::TryFrom;
+    /// use lib_tcstring::{EncodeOptions, VendorEncoding};
+    /// let tc_model = lib_tcstring::TcModelV2::try_from("COvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAAAEEUACCKAAA").unwrap();
+    /// // forces the range encoding for all vendor sections
+    /// let tc_string = tc_model.encode_with_options(&EncodeOptions {
+    ///     vendor_encoding: VendorEncoding::Range,
+    /// });
+    /// ```
+    ///
+    /// [`EncodeOptions`]: struct.EncodeOptions.html
+    pub fn encode_with_options(&self, options: &EncodeOptions) -> Result<String, TcsError> {
+        let mut tcs_segments: Vec<Vec<u8>> = Vec::with_capacity(4);
+
+        tcs_segments.push(encode_core_segment(self, options)?);
+
+        if !self.disclosed_vendors.is_empty() {
+            tcs_segments.push(encode_vendor_segment(1, &self.disclosed_vendors, options)?);
+        }
+
+        if !self.allowed_vendors.is_empty() {
+            tcs_segments.push(encode_vendor_segment(2, &self.allowed_vendors, options)?);
+        }
+
+        if !self.publisher_purposes_consent.is_empty()
+            || !self.publisher_purposes_li_transparency.is_empty()
+            || !self.custom_purposes_consent.is_empty()
+            || !self.custom_purposes_li_transparency.is_empty()
+        {
+            tcs_segments.push(encode_publisher_tc_segment(self)?);
+        }
+
+        Ok(tcs_segments
+            .iter()
+            .map(|segment| BASE64_ENGINE.encode(segment))
+            .collect::<Vec<String>>()
+            .join("."))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use super::*;
+    use crate::decode::fixtures::TC_STRINGS;
+
+    #[test]
+    fn iab_tcf_v2_round_trip() {
+        for tc_string in TC_STRINGS {
+            let tc_model = TcModelV2::try_from(*tc_string).unwrap();
+            let encoded = tc_model.encode().unwrap();
+
+            assert!(encoded.starts_with('C'), "{}", tc_string);
+            assert_eq!(
+                TcModelV2::try_from(encoded.as_str()),
+                Ok(tc_model),
+                "{}",
+                tc_string
+            );
+        }
+    }
+
+    #[test]
+    fn iab_tcf_v2_round_trip_vendor_encoding() {
+        for vendor_encoding in [VendorEncoding::BitField, VendorEncoding::Range] {
+            let options = EncodeOptions { vendor_encoding };
+
+            for tc_string in TC_STRINGS {
+                let tc_model = TcModelV2::try_from(*tc_string).unwrap();
+                let encoded = tc_model.encode_with_options(&options).unwrap();
+
+                assert_eq!(
+                    TcModelV2::try_from(encoded.as_str()),
+                    Ok(tc_model),
+                    "{:?} {}",
+                    vendor_encoding,
+                    tc_string
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn iab_tcf_v2_encode_shortest_vendor_encoding() {
+        let tc_model = TcModelV2 {
+            consent_language: String::from("EN"),
+            publisher_country_code: String::from("DE"),
+            vendors_consent: (1..=800).collect(),
+            vendors_li_consent: vec![2, 6, 8].into(),
+            ..TcModelV2::default()
+        };
+        let encoded_length = |vendor_encoding| {
+            tc_model
+                .encode_with_options(&EncodeOptions { vendor_encoding })
+                .unwrap()
+                .len()
+        };
+
+        assert!(encoded_length(VendorEncoding::Auto) < encoded_length(VendorEncoding::BitField));
+        assert!(encoded_length(VendorEncoding::Auto) < encoded_length(VendorEncoding::Range));
+        assert_eq!(
+            TcModelV2::try_from(tc_model.encode().unwrap().as_str()),
+            Ok(tc_model)
+        );
+    }
+
+    #[test]
+    fn iab_tcf_v2_encode_core_only() {
+        assert_eq!(
+            TcModelV2::try_from("COvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAAAEEUACCKAAA")
+                .unwrap()
+                .encode(),
+            Ok(String::from(
+                "COvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAAAEEUACCKAAA"
+            ))
+        );
+    }
+
+    #[test]
+    fn iab_tcf_v2_encode_invalid_values() {
+        let tc_model = TcModelV2 {
+            consent_language: String::from("EN"),
+            publisher_country_code: String::from("DE"),
+            ..TcModelV2::default()
+        };
+
+        assert_eq!(
+            TcModelV2 {
+                consent_language: String::from("en"),
+                ..tc_model.clone()
+            }
+            .encode(),
+            Err(TcsError::InvalidAlphabetOffset)
+        );
+        assert_eq!(
+            TcModelV2 {
+                purposes_consent: vec![25],
+                ..tc_model.clone()
+            }
+            .encode(),
+            Err(TcsError::ValueOutOfRange)
+        );
+        assert_eq!(
+            TcModelV2 {
+                cmp_id: 4096,
+                ..tc_model.clone()
+            }
+            .encode(),
+            Err(TcsError::ValueOutOfRange)
+        );
+        assert_eq!(
+            TcModelV2 {
+                vendors_consent: vec![0].into(),
+                ..tc_model
+            }
+            .encode(),
+            Err(TcsError::ValueOutOfRange)
+        );
+    }
+}
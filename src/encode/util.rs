@@ -0,0 +1,195 @@
+use crate::decode::{error::TcsError, model::VendorSet};
+
+#[derive(Default)]
+pub(crate) struct BitWriter {
+    bytes: Vec<u8>,
+    bit_length: usize,
+}
+
+impl BitWriter {
+    pub(crate) fn push(&mut self, value: u64, bit_length: usize) -> Result<(), TcsError> {
+        if bit_length < 64 && value >> bit_length != 0 {
+            return Err(TcsError::ValueOutOfRange);
+        }
+
+        for bit_index in (0..bit_length).rev() {
+            self.push_bool((value >> bit_index) & 1 == 1);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn push_bool(&mut self, value: bool) {
+        if self.bit_length.is_multiple_of(8) {
+            self.bytes.push(0);
+        }
+
+        if value {
+            let last_byte = self.bytes.len() - 1;
+
+            self.bytes[last_byte] |= 0x80 >> (self.bit_length % 8);
+        }
+
+        self.bit_length += 1;
+    }
+
+    pub(crate) fn push_string(
+        &mut self,
+        val: &str,
+        bit_width: usize,
+        char_count: usize,
+    ) -> Result<(), TcsError> {
+        if val.len() != char_count {
+            return Err(TcsError::ValueOutOfRange);
+        }
+
+        for character in val.bytes() {
+            if !character.is_ascii_uppercase() {
+                return Err(TcsError::InvalidAlphabetOffset);
+            }
+
+            self.push((character - b'A') as u64, bit_width)?;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn push_bitfield<T>(&mut self, ids: &[T], bit_length: usize) -> Result<(), TcsError>
+    where
+        T: Copy + Into<u64>,
+    {
+        let mut bitfield = vec![false; bit_length];
+
+        for id in ids {
+            let id: u64 = (*id).into();
+
+            if id == 0 || id > bit_length as u64 {
+                return Err(TcsError::ValueOutOfRange);
+            }
+
+            bitfield[(id - 1) as usize] = true;
+        }
+
+        for value in bitfield {
+            self.push_bool(value);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn push_vendor_bitfield(&mut self, vendor_list: &VendorSet, bit_length: usize) {
+        for vendor_id in 1..=bit_length {
+            self.push_bool(vendor_list.contains(vendor_id as u16));
+        }
+    }
+
+    pub(crate) fn push_vendor_range(&mut self, vendor_list: &VendorSet) -> Result<(), TcsError> {
+        self.push_vendor_ranges(&vendor_ranges(vendor_list)?)
+    }
+
+    pub(crate) fn push_vendor_ranges(&mut self, ranges: &[(u16, u16)]) -> Result<(), TcsError> {
+        self.push(ranges.len() as u64, 12)?;
+
+        for &(start_vendor_id, end_vendor_id) in ranges {
+            if start_vendor_id == end_vendor_id {
+                self.push_bool(false);
+                self.push(start_vendor_id as u64, 16)?;
+            } else {
+                self.push_bool(true);
+                self.push(start_vendor_id as u64, 16)?;
+                self.push(end_vendor_id as u64, 16)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+pub(crate) fn max_vendor_id(vendor_list: &VendorSet) -> u16 {
+    vendor_list.max_vendor_id().unwrap_or_default()
+}
+
+pub(crate) fn vendor_range_bit_length(ranges: &[(u16, u16)]) -> usize {
+    ranges
+        .iter()
+        .fold(12, |bit_length, (start_vendor_id, end_vendor_id)| {
+            bit_length
+                + if start_vendor_id == end_vendor_id {
+                    17
+                } else {
+                    33
+                }
+        })
+}
+
+pub(crate) fn vendor_ranges(vendor_list: &VendorSet) -> Result<Vec<(u16, u16)>, TcsError> {
+    let mut ranges: Vec<(u16, u16)> = Vec::new();
+
+    for vendor_id in vendor_list {
+        if vendor_id == 0 {
+            return Err(TcsError::ValueOutOfRange);
+        }
+
+        match ranges.last_mut() {
+            Some((_, end_vendor_id)) if *end_vendor_id + 1 == vendor_id => {
+                *end_vendor_id = vendor_id
+            }
+            _ => ranges.push((vendor_id, vendor_id)),
+        }
+    }
+
+    Ok(ranges)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bit_writer_push() {
+        let mut writer = BitWriter::default();
+
+        assert_eq!(writer.push(2, 6), Ok(()));
+        assert_eq!(writer.push(0b1011, 4), Ok(()));
+        assert_eq!(writer.push(64, 6), Err(TcsError::ValueOutOfRange));
+        assert_eq!(writer.into_bytes(), vec![0b0000_1010, 0b1100_0000]);
+    }
+
+    #[test]
+    fn bit_writer_push_string() {
+        let mut writer = BitWriter::default();
+
+        assert_eq!(writer.push_string("EN", 6, 2), Ok(()));
+        assert_eq!(
+            writer.push_string("en", 6, 2),
+            Err(TcsError::InvalidAlphabetOffset)
+        );
+        assert_eq!(
+            writer.push_string("ENG", 6, 2),
+            Err(TcsError::ValueOutOfRange)
+        );
+        assert_eq!(writer.into_bytes(), vec![0b0001_0000, 0b1101_0000]);
+    }
+
+    #[test]
+    fn vendor_ranges_merge_consecutive_ids() {
+        assert_eq!(
+            vendor_ranges(&vec![8, 2, 3, 4, 6, 3].into()),
+            Ok(vec![(2, 4), (6, 6), (8, 8)])
+        );
+        assert_eq!(
+            vendor_ranges(&vec![0].into()),
+            Err(TcsError::ValueOutOfRange)
+        );
+    }
+
+    #[test]
+    fn vendor_range_bit_length_of_entries() {
+        assert_eq!(vendor_range_bit_length(&[]), 12);
+        assert_eq!(vendor_range_bit_length(&[(2, 4), (6, 6)]), 12 + 33 + 17);
+    }
+}
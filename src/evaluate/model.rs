@@ -0,0 +1,86 @@
+/// Purposes a vendor declared in the global vendor list
+///
+/// With the `gvl` feature it can be created from a [`gvl::Vendor`]
+///
+/// [`gvl::Vendor`]: gvl/struct.Vendor.html
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Clone, PartialOrd, Hash, Debug, Default)]
+pub struct VendorDeclaration {
+    /// ID of the vendor
+    pub id: u16,
+    /// List of purposes declared on the legal basis of consent
+    pub purposes: Vec<u8>,
+    /// List of purposes declared on the legal basis of "Legitimate Interest"
+    pub leg_int_purposes: Vec<u8>,
+    /// List of purposes where the vendor allows the publisher to change the legal basis
+    pub flexible_purposes: Vec<u8>,
+}
+
+/// Legal basis on which a vendor may process data for a purpose
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Clone, Copy, PartialOrd, Hash, Debug)]
+pub enum LegalBasis {
+    /// User consented to the purpose and the vendor
+    Consent,
+    /// "Legitimate Interest" was disclosed for the purpose and the vendor and the user didn't object
+    LegitimateInterest,
+}
+
+/// Reason for the result of a [`PurposeEvaluation`]
+///
+/// [`PurposeEvaluation`]: struct.PurposeEvaluation.html
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Clone, Copy, PartialOrd, Hash, Debug)]
+pub enum LegalBasisReason {
+    /// Purpose and vendor consent is given
+    ConsentGiven,
+    /// Purpose and vendor "Legitimate Interest" is established
+    LegitimateInterestEstablished,
+    /// Vendor didn't declare the purpose
+    NotDeclared,
+    /// Publisher restricted the purpose with [`PublisherRestrictionType::NotAllowed`]
+    ///
+    /// [`PublisherRestrictionType::NotAllowed`]: enum.PublisherRestrictionType.html#variant.NotAllowed
+    NotAllowedByPublisher,
+    /// Publisher requires a legal basis which the vendor didn't declare and the purpose isn't flexible
+    PublisherRestrictionNotSatisfiable,
+    /// User didn't consent to the purpose
+    MissingPurposeConsent,
+    /// User didn't consent to the vendor
+    MissingVendorConsent,
+    /// "Legitimate Interest" isn't established for the purpose
+    MissingPurposeLegitimateInterest,
+    /// "Legitimate Interest" isn't established for the vendor
+    MissingVendorLegitimateInterest,
+    /// TCF policy doesn't allow "Legitimate Interest" for the purpose, which applies to purpose 1
+    /// and depends on the [`TcfPolicyVersion`] for other purposes
+    ///
+    /// [`TcfPolicyVersion`]: enum.TcfPolicyVersion.html
+    LegitimateInterestNotAllowedByPolicy,
+}
+
+/// Effective legal basis of a vendor for a purpose
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Clone, Copy, PartialOrd, Hash, Debug)]
+pub struct PurposeEvaluation {
+    /// ID of the evaluated purpose
+    pub purpose_id: u8,
+    /// Legal basis on which the vendor may process data, `None` if processing isn't allowed
+    pub legal_basis: Option<LegalBasis>,
+    /// Reason for the legal basis, see [`LegalBasisReason`] for more details
+    ///
+    /// [`LegalBasisReason`]: enum.LegalBasisReason.html
+    pub reason: LegalBasisReason,
+}
+
+#[cfg(feature = "gvl")]
+impl From<&crate::gvl::Vendor> for VendorDeclaration {
+    fn from(vendor: &crate::gvl::Vendor) -> Self {
+        Self {
+            id: vendor.id,
+            purposes: vendor.purposes.clone(),
+            leg_int_purposes: vendor.leg_int_purposes.clone(),
+            flexible_purposes: vendor.flexible_purposes.clone(),
+        }
+    }
+}
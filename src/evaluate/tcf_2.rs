@@ -0,0 +1,381 @@
+use crate::{
+    decode::model::{PublisherRestrictionType, TcModelV2},
+    evaluate::model::{LegalBasis, LegalBasisReason, PurposeEvaluation, VendorDeclaration},
+};
+
+/// Purpose for which the TCF policy never allows "Legitimate Interest" as legal basis
+const CONSENT_ONLY_PURPOSE_ID: u8 = 1;
+
+impl TcModelV2 {
+    fn evaluate_legal_basis(
+        &self,
+        purpose_id: u8,
+        vendor_id: u16,
+        legal_basis: LegalBasis,
+    ) -> PurposeEvaluation {
+        if legal_basis == LegalBasis::LegitimateInterest
+            && (purpose_id == CONSENT_ONLY_PURPOSE_ID
+                || !self.policy_version().allows_legitimate_interest(purpose_id))
+        {
+            return PurposeEvaluation {
+                purpose_id,
+                legal_basis: None,
+                reason: LegalBasisReason::LegitimateInterestNotAllowedByPolicy,
+            };
+        }
+
+        let (has_purpose, has_vendor, reasons) = match legal_basis {
+            LegalBasis::Consent => (
+                self.purposes_consent.contains(&purpose_id),
+                self.vendors_consent.contains(vendor_id),
+                (
+                    LegalBasisReason::ConsentGiven,
+                    LegalBasisReason::MissingPurposeConsent,
+                    LegalBasisReason::MissingVendorConsent,
+                ),
+            ),
+            LegalBasis::LegitimateInterest => (
+                self.purposes_li_transparency.contains(&purpose_id),
+                self.vendors_li_consent.contains(vendor_id),
+                (
+                    LegalBasisReason::LegitimateInterestEstablished,
+                    LegalBasisReason::MissingPurposeLegitimateInterest,
+                    LegalBasisReason::MissingVendorLegitimateInterest,
+                ),
+            ),
+        };
+        let (legal_basis, reason) = match (has_purpose, has_vendor) {
+            (true, true) => (Some(legal_basis), reasons.0),
+            (false, _) => (None, reasons.1),
+            (true, false) => (None, reasons.2),
+        };
+
+        PurposeEvaluation {
+            purpose_id,
+            legal_basis,
+            reason,
+        }
+    }
+
+    /// Evaluates the effective legal basis of the given vendor for a single purpose
+    ///
+    /// The declared legal basis of the vendor is used, unless a publisher restriction applies
+    /// * [`PublisherRestrictionType::NotAllowed`] disallows the purpose
+    /// * [`PublisherRestrictionType::RequireConsent`] and [`PublisherRestrictionType::RequireLegitimateInterest`]
+    ///   change the legal basis if the vendor declared the purpose as flexible,
+    ///   otherwise the purpose is disallowed unless the vendor already declared the required legal basis
+    ///
+    /// Purpose 1 is only allowed on the legal basis of consent, a [`PublisherRestrictionType::RequireLegitimateInterest`]
+    /// restriction for it can't be satisfied
+    ///
+    /// ```rust,edition2021
+    /// use std::convert::TryFrom;
+    /// use lib_tcstring::{LegalBasis, TcModelV2, VendorDeclaration};
+    /// let tc_model = TcModelV2::try_from("COvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAAAEEUACCKAAA").unwrap();
+    /// let vendor = VendorDeclaration {
+    ///     id: 8,
+    ///     purposes: vec![1, 3],
+    ///     leg_int_purposes: vec![2],
+    ///     flexible_purposes: vec![2],
+    /// };
+    ///
+    /// assert_eq!(
+    ///     tc_model.evaluate_vendor_purpose(&vendor, 3).legal_basis,
+    ///     Some(LegalBasis::Consent)
+    /// );
+    /// ```
+    ///
+    /// [`PublisherRestrictionType::NotAllowed`]: enum.PublisherRestrictionType.html#variant.NotAllowed
+    /// [`PublisherRestrictionType::RequireConsent`]: enum.PublisherRestrictionType.html#variant.RequireConsent
+    /// [`PublisherRestrictionType::RequireLegitimateInterest`]: enum.PublisherRestrictionType.html#variant.RequireLegitimateInterest
+    pub fn evaluate_vendor_purpose(
+        &self,
+        vendor: &VendorDeclaration,
+        purpose_id: u8,
+    ) -> PurposeEvaluation {
+        let declared_legal_basis = if vendor.purposes.contains(&purpose_id) {
+            LegalBasis::Consent
+        } else if vendor.leg_int_purposes.contains(&purpose_id) {
+            LegalBasis::LegitimateInterest
+        } else {
+            return PurposeEvaluation {
+                purpose_id,
+                legal_basis: None,
+                reason: LegalBasisReason::NotDeclared,
+            };
+        };
+        let required_legal_basis = match self
+            .publisher_restrictions
+            .restriction_for(purpose_id, vendor.id)
+        {
+            Some(PublisherRestrictionType::NotAllowed) => {
+                return PurposeEvaluation {
+                    purpose_id,
+                    legal_basis: None,
+                    reason: LegalBasisReason::NotAllowedByPublisher,
+                }
+            }
+            Some(PublisherRestrictionType::RequireConsent) => LegalBasis::Consent,
+            Some(PublisherRestrictionType::RequireLegitimateInterest)
+                if purpose_id == CONSENT_ONLY_PURPOSE_ID =>
+            {
+                return PurposeEvaluation {
+                    purpose_id,
+                    legal_basis: None,
+                    reason: LegalBasisReason::PublisherRestrictionNotSatisfiable,
+                }
+            }
+            Some(PublisherRestrictionType::RequireLegitimateInterest) => {
+                LegalBasis::LegitimateInterest
+            }
+            Some(PublisherRestrictionType::Undefined) | None => declared_legal_basis,
+        };
+
+        if required_legal_basis != declared_legal_basis
+            && !vendor.flexible_purposes.contains(&purpose_id)
+        {
+            return PurposeEvaluation {
+                purpose_id,
+                legal_basis: None,
+                reason: LegalBasisReason::PublisherRestrictionNotSatisfiable,
+            };
+        }
+
+        self.evaluate_legal_basis(purpose_id, vendor.id, required_legal_basis)
+    }
+
+    /// Evaluates the effective legal basis of the given vendor for every declared purpose, ordered by purpose ID
+    ///
+    /// See [`evaluate_vendor_purpose`] for the applied rules
+    ///
+    /// [`evaluate_vendor_purpose`]: struct.TcModelV2.html#method.evaluate_vendor_purpose
+    pub fn evaluate_vendor(&self, vendor: &VendorDeclaration) -> Vec<PurposeEvaluation> {
+        let mut purpose_ids: Vec<u8> = vendor
+            .purposes
+            .iter()
+            .chain(vendor.leg_int_purposes.iter())
+            .copied()
+            .collect();
+
+        purpose_ids.sort_unstable();
+        purpose_ids.dedup();
+
+        purpose_ids
+            .into_iter()
+            .map(|purpose_id| self.evaluate_vendor_purpose(vendor, purpose_id))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encode::builder::TcModelV2Builder;
+
+    fn vendor() -> VendorDeclaration {
+        VendorDeclaration {
+            id: 755,
+            purposes: vec![1, 3, 4],
+            leg_int_purposes: vec![2, 7, 8],
+            flexible_purposes: vec![2, 7],
+        }
+    }
+
+    fn evaluation(
+        purpose_id: u8,
+        legal_basis: Option<LegalBasis>,
+        reason: LegalBasisReason,
+    ) -> PurposeEvaluation {
+        PurposeEvaluation {
+            purpose_id,
+            legal_basis,
+            reason,
+        }
+    }
+
+    #[test]
+    fn evaluate_vendor_without_restrictions() {
+        let tc_model = TcModelV2Builder::default()
+            .consent_purpose(1)
+            .and_then(|builder| builder.consent_purpose(3))
+            .and_then(|builder| builder.legitimate_interest_purpose(2))
+            .and_then(|builder| builder.legitimate_interest_purpose(8))
+            .and_then(|builder| builder.vendor_consent(755))
+            .and_then(|builder| builder.vendor_legitimate_interest(1))
+            .unwrap()
+            .build();
+
+        assert_eq!(
+            tc_model.evaluate_vendor(&vendor()),
+            vec![
+                evaluation(1, Some(LegalBasis::Consent), LegalBasisReason::ConsentGiven),
+                evaluation(2, None, LegalBasisReason::MissingVendorLegitimateInterest),
+                evaluation(3, Some(LegalBasis::Consent), LegalBasisReason::ConsentGiven),
+                evaluation(4, None, LegalBasisReason::MissingPurposeConsent),
+                evaluation(7, None, LegalBasisReason::MissingPurposeLegitimateInterest),
+                evaluation(8, None, LegalBasisReason::MissingVendorLegitimateInterest),
+            ]
+        );
+        assert_eq!(
+            tc_model.evaluate_vendor_purpose(&vendor(), 5),
+            evaluation(5, None, LegalBasisReason::NotDeclared)
+        );
+    }
+
+    #[test]
+    fn evaluate_vendor_with_restrictions() {
+        let tc_model = TcModelV2Builder::default()
+            .consent_purpose(2)
+            .and_then(|builder| builder.consent_purpose(3))
+            .and_then(|builder| builder.consent_purpose(4))
+            .and_then(|builder| builder.legitimate_interest_purpose(8))
+            .and_then(|builder| builder.vendor_consent(755))
+            .and_then(|builder| builder.vendor_legitimate_interest(755))
+            .and_then(|builder| {
+                builder.restrict(2, PublisherRestrictionType::RequireConsent, &[755])
+            })
+            .and_then(|builder| builder.restrict(3, PublisherRestrictionType::NotAllowed, &[755]))
+            .and_then(|builder| {
+                builder.restrict(
+                    4,
+                    PublisherRestrictionType::RequireLegitimateInterest,
+                    &[755],
+                )
+            })
+            .and_then(|builder| {
+                builder.restrict(8, PublisherRestrictionType::RequireConsent, &[755])
+            })
+            .and_then(|builder| builder.restrict(8, PublisherRestrictionType::NotAllowed, &[1]))
+            .unwrap()
+            .build();
+
+        assert_eq!(
+            tc_model.evaluate_vendor(&vendor())[1..],
+            vec![
+                evaluation(2, Some(LegalBasis::Consent), LegalBasisReason::ConsentGiven),
+                evaluation(3, None, LegalBasisReason::NotAllowedByPublisher),
+                evaluation(
+                    4,
+                    None,
+                    LegalBasisReason::PublisherRestrictionNotSatisfiable
+                ),
+                evaluation(7, None, LegalBasisReason::MissingPurposeLegitimateInterest),
+                evaluation(
+                    8,
+                    None,
+                    LegalBasisReason::PublisherRestrictionNotSatisfiable
+                ),
+            ][..]
+        );
+    }
+
+    #[test]
+    fn evaluate_vendor_purpose_one() {
+        let vendor = VendorDeclaration {
+            id: 755,
+            purposes: vec![1],
+            leg_int_purposes: vec![],
+            flexible_purposes: vec![1],
+        };
+        let tc_model = TcModelV2Builder::default()
+            .consent_purpose(1)
+            .and_then(|builder| builder.legitimate_interest_purpose(1))
+            .and_then(|builder| builder.vendor_consent(755))
+            .and_then(|builder| builder.vendor_legitimate_interest(755))
+            .and_then(|builder| builder.vendor_consent(8))
+            .and_then(|builder| builder.vendor_legitimate_interest(8))
+            .and_then(|builder| {
+                builder.restrict(
+                    1,
+                    PublisherRestrictionType::RequireLegitimateInterest,
+                    &[755],
+                )
+            })
+            .unwrap()
+            .build();
+
+        assert_eq!(
+            tc_model.evaluate_vendor_purpose(&vendor, 1),
+            evaluation(
+                1,
+                None,
+                LegalBasisReason::PublisherRestrictionNotSatisfiable
+            )
+        );
+        assert_eq!(
+            tc_model.evaluate_vendor_purpose(
+                &VendorDeclaration {
+                    id: 8,
+                    purposes: vec![],
+                    leg_int_purposes: vec![1],
+                    flexible_purposes: vec![],
+                },
+                1
+            ),
+            evaluation(
+                1,
+                None,
+                LegalBasisReason::LegitimateInterestNotAllowedByPolicy
+            )
+        );
+        assert_eq!(
+            tc_model.evaluate_vendor_purpose(&VendorDeclaration { id: 8, ..vendor }, 1),
+            evaluation(1, Some(LegalBasis::Consent), LegalBasisReason::ConsentGiven)
+        );
+    }
+
+    #[test]
+    fn evaluate_vendor_with_policy_version() {
+        let vendor = VendorDeclaration {
+            id: 755,
+            purposes: vec![],
+            leg_int_purposes: vec![4, 7],
+            flexible_purposes: vec![],
+        };
+        let builder = TcModelV2Builder::default()
+            .legitimate_interest_purpose(4)
+            .and_then(|builder| builder.legitimate_interest_purpose(7))
+            .and_then(|builder| builder.vendor_legitimate_interest(755))
+            .unwrap();
+
+        assert_eq!(
+            builder
+                .clone()
+                .tcf_policy_version(2)
+                .unwrap()
+                .build()
+                .evaluate_vendor(&vendor),
+            vec![
+                evaluation(
+                    4,
+                    Some(LegalBasis::LegitimateInterest),
+                    LegalBasisReason::LegitimateInterestEstablished
+                ),
+                evaluation(
+                    7,
+                    Some(LegalBasis::LegitimateInterest),
+                    LegalBasisReason::LegitimateInterestEstablished
+                ),
+            ]
+        );
+        assert_eq!(
+            builder
+                .tcf_policy_version(4)
+                .unwrap()
+                .build()
+                .evaluate_vendor(&vendor),
+            vec![
+                evaluation(
+                    4,
+                    None,
+                    LegalBasisReason::LegitimateInterestNotAllowedByPolicy
+                ),
+                evaluation(
+                    7,
+                    Some(LegalBasis::LegitimateInterest),
+                    LegalBasisReason::LegitimateInterestEstablished
+                ),
+            ]
+        );
+    }
+}
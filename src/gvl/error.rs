@@ -0,0 +1,34 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+};
+
+pub const IO: &str = "ERR_IO";
+pub const INVALID_JSON: &str = "ERR_INVALID_JSON";
+
+/// Errors that can occur while loading the global vendor list
+#[derive(Debug)]
+pub enum GvlError {
+    /// Global vendor list file couldn't be read
+    Io(std::io::Error),
+    /// Global vendor list isn't valid JSON or doesn't match the schema
+    InvalidJson(serde_json::Error),
+}
+
+impl Display for GvlError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GvlError::Io(io_error) => write!(f, "{}: {}", IO, io_error),
+            GvlError::InvalidJson(json_error) => write!(f, "{}: {}", INVALID_JSON, json_error),
+        }
+    }
+}
+
+impl Error for GvlError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            GvlError::Io(err) => Some(err),
+            GvlError::InvalidJson(err) => Some(err),
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use std::{fs::File, io::Read, path::Path, str::FromStr};
+
+use crate::gvl::{error::GvlError, model::VendorList};
+
+impl FromStr for VendorList {
+    type Err = GvlError;
+
+    fn from_str(val: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(val).map_err(GvlError::InvalidJson)
+    }
+}
+
+impl VendorList {
+    /// Reads the global vendor list from the given reader
+    pub fn from_reader<R: Read>(reader: R) -> Result<Self, GvlError> {
+        serde_json::from_reader(reader).map_err(GvlError::InvalidJson)
+    }
+
+    /// Reads the global vendor list from the given file
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, GvlError> {
+        Self::from_reader(std::io::BufReader::new(
+            File::open(path).map_err(GvlError::Io)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VENDOR_LIST_V2: &str = r#"{
+        "gvlSpecificationVersion": 2,
+        "vendorListVersion": 68,
+        "tcfPolicyVersion": 2,
+        "lastUpdated": "2020-12-10T16:05:24Z",
+        "purposes": {
+            "1": {
+                "id": 1,
+                "name": "Store and/or access information on a device",
+                "description": "Cookies, device identifiers, or other information can be stored or accessed on your device for the purposes presented to you.",
+                "descriptionLegal": "Vendors can: Store and access information on the device such as cookies and device identifiers presented to a user."
+            }
+        },
+        "specialPurposes": {
+            "1": {
+                "id": 1,
+                "name": "Ensure security, prevent fraud, and debug",
+                "description": "Your data can be used to monitor for and prevent fraudulent activity.",
+                "descriptionLegal": "To ensure security, prevent fraud and debug vendors can: Ensure data are securely transmitted."
+            }
+        },
+        "features": {},
+        "specialFeatures": {
+            "1": {
+                "id": 1,
+                "name": "Use precise geolocation data",
+                "description": "Your precise geolocation data can be used in support of one or more purposes.",
+                "descriptionLegal": "Vendors can: Collect and process precise geolocation data in support of one or more purposes."
+            }
+        },
+        "stacks": {
+            "1": {
+                "id": 1,
+                "purposes": [],
+                "specialFeatures": [1],
+                "name": "Precise geolocation data",
+                "description": "Precise geolocation data can be used in support of one or more purposes."
+            }
+        },
+        "vendors": {
+            "755": {
+                "id": 755,
+                "name": "Google Advertising Products",
+                "purposes": [1],
+                "legIntPurposes": [2, 7],
+                "flexiblePurposes": [2, 7],
+                "specialPurposes": [1],
+                "features": [],
+                "specialFeatures": [],
+                "policyUrl": "https://policies.google.com/privacy",
+                "cookieMaxAgeSeconds": 34190000,
+                "usesCookies": true,
+                "cookieRefresh": false,
+                "usesNonCookieAccess": true
+            }
+        }
+    }"#;
+
+    const VENDOR_LIST_V3: &str = r#"{
+        "gvlSpecificationVersion": 3,
+        "vendorListVersion": 3,
+        "tcfPolicyVersion": 4,
+        "lastUpdated": "2023-05-11T16:04:33Z",
+        "purposes": {
+            "11": {
+                "id": 11,
+                "name": "Use limited data to select content",
+                "description": "Content presented to you on this service can be based on limited data.",
+                "illustrations": ["A travel magazine has published an article on its website."],
+                "consentable": true,
+                "rightToObject": true
+            }
+        },
+        "specialPurposes": {},
+        "features": {},
+        "specialFeatures": {},
+        "stacks": {},
+        "dataCategories": {
+            "1": {
+                "id": 1,
+                "name": "IP addresses",
+                "description": "Your IP address is a number assigned by your Internet Service Provider."
+            }
+        },
+        "vendors": {
+            "1": {
+                "id": 1,
+                "name": "Exponential Interactive, Inc d/b/a VDX.tv",
+                "purposes": [1, 11],
+                "legIntPurposes": [],
+                "flexiblePurposes": [],
+                "specialPurposes": [],
+                "features": [],
+                "specialFeatures": [],
+                "cookieMaxAgeSeconds": 7776000,
+                "usesCookies": true,
+                "cookieRefresh": false,
+                "urls": [
+                    {
+                        "langId": "en",
+                        "privacy": "https://vdx.tv/privacy/",
+                        "legIntClaim": "https://cdnx.exponential.com/wp-content/uploads/2018/04/Balancing-Assessment-for-Legitimate-Interest-Publishers-v2.pdf"
+                    }
+                ],
+                "usesNonCookieAccess": false,
+                "dataRetention": {
+                    "stdRetention": 90,
+                    "purposes": {},
+                    "specialPurposes": {}
+                },
+                "dataDeclaration": [1]
+            }
+        }
+    }"#;
+
+    #[test]
+    fn gvl_v2() {
+        let vendor_list = VendorList::from_str(VENDOR_LIST_V2).unwrap();
+        let vendor = &vendor_list.vendors[&755];
+
+        assert_eq!(vendor_list.vendor_list_version, 68);
+        assert_eq!(
+            vendor_list.purposes[&1].name,
+            "Store and/or access information on a device"
+        );
+        assert!(vendor_list.purposes[&1].description_legal.is_some());
+        assert_eq!(vendor_list.stacks[&1].special_features, vec![1]);
+        assert_eq!(vendor.name, "Google Advertising Products");
+        assert_eq!(vendor.leg_int_purposes, vec![2, 7]);
+        assert_eq!(vendor.flexible_purposes, vec![2, 7]);
+        assert_eq!(
+            vendor.policy_url.as_deref(),
+            Some("https://policies.google.com/privacy")
+        );
+    }
+
+    #[test]
+    fn gvl_v3() {
+        let vendor_list = VendorList::from_reader(VENDOR_LIST_V3.as_bytes()).unwrap();
+        let vendor = &vendor_list.vendors[&1];
+
+        assert_eq!(vendor_list.gvl_specification_version, 3);
+        assert_eq!(vendor_list.purposes[&11].consentable, Some(true));
+        assert_eq!(vendor_list.data_categories[&1].name, "IP addresses");
+        assert_eq!(vendor.purposes, vec![1, 11]);
+        assert_eq!(vendor.urls[0].lang_id, "en");
+        assert_eq!(
+            vendor
+                .data_retention
+                .as_ref()
+                .and_then(|data_retention| data_retention.std_retention),
+            Some(90)
+        );
+        assert_eq!(vendor.data_declaration, vec![1]);
+    }
+
+    #[test]
+    fn gvl_invalid() {
+        assert!(matches!(
+            VendorList::from_str("{}"),
+            Err(GvlError::InvalidJson(_))
+        ));
+        assert!(matches!(
+            VendorList::from_path("does-not-exist.json"),
+            Err(GvlError::Io(_))
+        ));
+    }
+}
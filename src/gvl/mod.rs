@@ -0,0 +1,19 @@
+//! Types for the IAB global vendor list (`vendor-list.json`) to resolve the IDs of a TC String
+//!
+//! Requires the `gvl` feature
+//!
+//! ```rust,edition2021,no_run
+//! use lib_tcstring::gvl::VendorList;
+//! // will return a Result which contains either the VendorList or an Error
+//! // if the file could not be read or doesn't contain a valid global vendor list
+//! let vendor_list = VendorList::from_path("vendor-list.json");
+//! ```
+
+pub use error::GvlError;
+pub use model::{
+    DataCategory, DataRetention, Feature, Purpose, Stack, Vendor, VendorList, VendorUrl,
+};
+
+mod error;
+mod load;
+mod model;
@@ -0,0 +1,185 @@
+use std::collections::BTreeMap;
+
+/// `VendorList` contains the IAB global vendor list (`vendor-list.json`) as specified in the
+/// [`Global Vendor List Format`], schema version 2 and 3 are supported
+///
+/// [`Global Vendor List Format`]: https://github.com/InteractiveAdvertisingBureau/GDPR-Transparency-and-Consent-Framework/blob/master/TCFv2/IAB%20Tech%20Lab%20-%20Consent%20string%20and%20vendor%20list%20formats%20v2.md#the-global-vendor-list
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct VendorList {
+    /// Version of the global vendor list schema
+    pub gvl_specification_version: u8,
+    /// Version of the global vendor list, matches `TcModelV2::vendor_list_version`
+    pub vendor_list_version: u16,
+    /// TCF policy version, matches `TcModelV2::tcf_policy_version`
+    pub tcf_policy_version: u16,
+    /// Timestamp of the last update
+    pub last_updated: String,
+    /// Purposes by ID
+    pub purposes: BTreeMap<u8, Purpose>,
+    /// Special purposes by ID
+    pub special_purposes: BTreeMap<u8, Purpose>,
+    /// Features by ID
+    pub features: BTreeMap<u8, Feature>,
+    /// Special features by ID
+    pub special_features: BTreeMap<u8, Feature>,
+    /// Stacks by ID
+    #[serde(default)]
+    pub stacks: BTreeMap<u16, Stack>,
+    /// Data categories by ID (schema version 3 only)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub data_categories: BTreeMap<u8, DataCategory>,
+    /// Vendors by ID
+    pub vendors: BTreeMap<u16, Vendor>,
+}
+
+/// Purpose or special purpose of the global vendor list
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Purpose {
+    /// ID of the purpose
+    pub id: u8,
+    /// Name of the purpose
+    pub name: String,
+    /// User friendly description of the purpose
+    pub description: String,
+    /// Legal description of the purpose (schema version 2 only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_legal: Option<String>,
+    /// Illustrations of the purpose (schema version 3 only)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub illustrations: Vec<String>,
+    /// Whether the purpose can be established on the legal basis of consent (schema version 3 only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub consentable: Option<bool>,
+    /// Whether the user can object to the purpose (schema version 3 only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub right_to_object: Option<bool>,
+}
+
+/// Feature or special feature of the global vendor list
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Feature {
+    /// ID of the feature
+    pub id: u8,
+    /// Name of the feature
+    pub name: String,
+    /// User friendly description of the feature
+    pub description: String,
+    /// Legal description of the feature (schema version 2 only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_legal: Option<String>,
+    /// Illustrations of the feature (schema version 3 only)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub illustrations: Vec<String>,
+}
+
+/// Stack which combines purposes and special features
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Stack {
+    /// ID of the stack
+    pub id: u16,
+    /// Name of the stack
+    pub name: String,
+    /// Description of the stack
+    pub description: String,
+    /// List of purpose IDs contained in the stack
+    pub purposes: Vec<u8>,
+    /// List of special feature IDs contained in the stack
+    pub special_features: Vec<u8>,
+}
+
+/// Category of data which is collected by vendors (schema version 3 only)
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DataCategory {
+    /// ID of the data category
+    pub id: u8,
+    /// Name of the data category
+    pub name: String,
+    /// Description of the data category
+    pub description: String,
+}
+
+/// Vendor of the global vendor list
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Vendor {
+    /// ID of the vendor
+    pub id: u16,
+    /// Name of the vendor
+    pub name: String,
+    /// List of purposes the vendor declared on the legal basis of consent
+    pub purposes: Vec<u8>,
+    /// List of purposes the vendor declared on the legal basis of "Legitimate Interest"
+    pub leg_int_purposes: Vec<u8>,
+    /// List of purposes where the vendor is flexible regarding the legal basis
+    pub flexible_purposes: Vec<u8>,
+    /// List of special purposes the vendor declared
+    pub special_purposes: Vec<u8>,
+    /// List of features the vendor declared
+    pub features: Vec<u8>,
+    /// List of special features the vendor declared
+    pub special_features: Vec<u8>,
+    /// URL of the privacy policy (schema version 2 only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub policy_url: Option<String>,
+    /// Privacy policy and "Legitimate Interest" claim URLs by language (schema version 3 only)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub urls: Vec<VendorUrl>,
+    /// Timestamp when the vendor was deleted from the global vendor list
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_date: Option<String>,
+    /// Whether the vendor uses cookies
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uses_cookies: Option<bool>,
+    /// Maximum age of cookies in seconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cookie_max_age_seconds: Option<i64>,
+    /// Whether cookies are refreshed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cookie_refresh: Option<bool>,
+    /// Whether the vendor uses other methods than cookies to access the device
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uses_non_cookie_access: Option<bool>,
+    /// URL of the device storage disclosure
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub device_storage_disclosure_url: Option<String>,
+    /// Data retention periods in days (schema version 3 only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_retention: Option<DataRetention>,
+    /// List of data category IDs the vendor collects (schema version 3 only)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub data_declaration: Vec<u8>,
+}
+
+/// Privacy policy and "Legitimate Interest" claim URLs of a vendor for one language
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct VendorUrl {
+    /// Language of the URLs
+    pub lang_id: String,
+    /// URL of the privacy policy
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub privacy: Option<String>,
+    /// URL of the "Legitimate Interest" claim
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub leg_int_claim: Option<String>,
+}
+
+/// Data retention periods of a vendor in days
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DataRetention {
+    /// Standard retention period which applies if no purpose specific period is set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub std_retention: Option<u32>,
+    /// Retention periods by purpose ID
+    #[serde(default)]
+    pub purposes: BTreeMap<u8, u32>,
+    /// Retention periods by special purpose ID
+    #[serde(default)]
+    pub special_purposes: BTreeMap<u8, u32>,
+}
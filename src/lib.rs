@@ -1,4 +1,4 @@
-//! # TCF String library which (currently only) decodes a given TCString
+//! # TCF String library which decodes and encodes a given TCString
 //!
 //! NOTE: This is not an official IAB library
 //!
@@ -7,6 +7,8 @@
 //! use std::convert::TryFrom;
 //! // returns a Result which contains a TcModel V2
 //! let tc_model_v2 = lib_tcstring::TcModelV2::try_from("COvFyGBOvFyGBAbAAAENAPCAAOAAAAAAAAAAAEEUACCKAAA");
+//! // returns a Result which contains the TCString of the TcModel V2
+//! let tc_string = tc_model_v2.unwrap().encode();
 //! ```
 
 #![warn(clippy::all)]
@@ -17,14 +19,33 @@
 #[cfg(feature = "serde")]
 extern crate serde;
 
+pub use cmp_api::model::{TcData, TcDataConsents, TcDataOutOfBand, TcDataPublisher};
 pub use decode::{
-    error::TcsError,
-    model::{PublisherRestriction, PublisherRestrictionType, TcModelV2},
+    error::{ErrorLocation, LocatedError, SegmentType, TcsError},
+    model::{
+        AdditionalConsent, BatchDecodeIter, BatchDecoder, CountryCode, DecodeOptions, GppSection,
+        GppSectionError, GppString, LanguageCode, PublisherRestriction, PublisherRestrictionType,
+        PublisherRestrictions, PublisherTc, Purpose, Segment, SpecialFeature, TcModel, TcModelV1,
+        TcModelV2, TcStringView, TcfPolicyVersion, UsPrivacy, UsPrivacyFlag, VendorSet,
+        VendorSetIter,
+    },
 };
+pub use diff::model::{Change, IdChanges, PublisherRestrictionChange, TcModelV2Diff};
+pub use encode::{
+    builder::TcModelV2Builder,
+    model::{EncodeOptions, VendorEncoding},
+};
+pub use evaluate::model::{LegalBasis, LegalBasisReason, PurposeEvaluation, VendorDeclaration};
 
 #[macro_use]
 mod macros;
+mod cmp_api;
 mod decode;
+mod diff;
+mod encode;
+mod evaluate;
+#[cfg(feature = "gvl")]
+pub mod gvl;
 
 mod tests {
     #[test]
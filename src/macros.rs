@@ -1,12 +1,14 @@
 #[doc(hidden)]
 #[macro_export]
 macro_rules! byte_list_bit_boundary_check {
-    ($byte_list: expr, $bit_index: expr) => {{
+    ($byte_list: expr, $bit_index: expr, $location: expr) => {{
         let length = $byte_list.len();
         let bit_index: usize = $bit_index;
 
         if length * 8 < bit_index {
-            return Err($crate::decode::error::TcsError::InsufficientLength);
+            return Err($crate::decode::error::TcsError::InsufficientLength
+                .at($location)
+                .into());
         }
     }};
 }
@@ -14,11 +16,13 @@ macro_rules! byte_list_bit_boundary_check {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! range_section_value {
-    ($sections: expr, $variant: path) => {{
+    ($sections: expr, $variant: path, $location: expr) => {{
         let sections = &mut $sections;
 
         if sections.is_empty() {
-            return Err($crate::decode::error::TcsError::InvalidSectionDefinition);
+            return Err($crate::decode::error::TcsError::InvalidSectionDefinition
+                .at($location)
+                .into());
         }
 
         if let RangeSection {
@@ -28,7 +32,9 @@ macro_rules! range_section_value {
         {
             section
         } else {
-            return Err($crate::decode::error::TcsError::InvalidSectionDefinition);
+            return Err($crate::decode::error::TcsError::InvalidSectionDefinition
+                .at($location)
+                .into());
         }
     }};
 }
@@ -41,10 +47,15 @@ macro_rules! parse_bitfield_from_bytes {
             val: &[u8],
             bit_start: usize,
             bit_length: usize,
-        ) -> Result<Vec<$type>, $crate::decode::error::TcsError> {
+            field: &'static str,
+        ) -> Result<Vec<$type>, $crate::decode::error::LocatedError> {
             let bit_end = bit_start + bit_length;
 
-            byte_list_bit_boundary_check!(val, bit_end);
+            byte_list_bit_boundary_check!(
+                val,
+                bit_end,
+                $crate::decode::error::ErrorLocation::new(field, bit_start)
+            );
 
             let mut result: Vec<$type> = Vec::with_capacity(bit_length);
 